@@ -58,11 +58,11 @@ impl Component for Model {
                     <h1>{ "Containers" }</h1>
                     <Container class="bg-primary">{"Normal"}</Container>
                     <Container class="bg-secondary" fluid={true}>{"Fluid"}</Container>
-                    <Container class="bg-success" size={ContainerSize::Small}>{"Small"}</Container>
-                    <Container class="bg-danger" size={ContainerSize::Medium}>{"Medium"}</Container>
-                    <Container class="bg-warning" size={ContainerSize::Large}>{"Large"}</Container>
-                    <Container class="bg-info" size={ContainerSize::ExtraLarge}>{"Extra Large"}</Container>
-                    <Container class="bg-light" size={ContainerSize::ExtraExtraLarge}>{"Extra Large"}</Container>
+                    <Container class="bg-success" size={Breakpoint::Sm}>{"Small"}</Container>
+                    <Container class="bg-danger" size={Breakpoint::Md}>{"Medium"}</Container>
+                    <Container class="bg-warning" size={Breakpoint::Lg}>{"Large"}</Container>
+                    <Container class="bg-info" size={Breakpoint::Xl}>{"Extra Large"}</Container>
+                    <Container class="bg-light" size={Breakpoint::Xxl}>{"Extra Large"}</Container>
 
                     <h1>{ "Grid" }</h1>
                     <Row>
@@ -213,6 +213,10 @@ impl Component for Model {
 
                     <h1>{"Modals"}</h1>
                     <Button style={Color::Primary} modal_target={"ExampleModal"}>{"Open Modal"}</Button>
+                    <ConfirmDialog id="ExampleConfirm" title="Delete item" confirm_text="Delete" confirm_style={Color::Danger}>
+                        {"This item will be permanently deleted."}
+                    </ConfirmDialog>
+                    <Button style={Color::Danger} modal_target={"ExampleConfirm"}>{"Open confirmation dialog"}</Button>
 
                     <h1>{"Buttons"}</h1>
                     <Button style={Color::Primary}>{"Primary"}</Button>
@@ -273,6 +277,7 @@ impl Component for Model {
                         <ListGroupItem>{"A"}</ListGroupItem>
                         <ListGroupItem active=true>{"B"}</ListGroupItem>
                         <ListGroupItem disabled=true>{"C"}</ListGroupItem>
+                        <ListGroupItem action=true loading=true>{"D (loading)"}</ListGroupItem>
                     </ListGroup>
 
                     <h2>{"Flush and with numbers"}</h2>
@@ -175,7 +175,7 @@ impl Component for Model {
                 <div id="forms" class="p-3">
                     <h1>{ "Forms" }</h1>
                     <h2>{ "Input types" }</h2>
-                    <Container size={ContainerSize::ExtraLarge}>
+                    <Container size={Breakpoint::Xl}>
                         <FormControl id="input-text" ctype={FormControlType::Text}
                             class="mb-3" label="Text" value="Initial text"/>
                         <FormControl id="input-textarea"
@@ -204,6 +204,12 @@ impl Component for Model {
                             ctype={ FormControlType::Range { min: -10, max: 10, step: Some(5)} }
                             class="mb-3" label="Range -10-10, step 5" value="-5"
                         />
+                        <FormControl
+                            id="input-range-value"
+                            ctype={ FormControlType::Range { min: 0, max: 100, step: None } }
+                            class="mb-3" label="Range with value display" value="50"
+                            show_value=true
+                        />
                         <FormControl
                             id="input-select1"
                             ctype={ FormControlType::Select}
@@ -259,6 +265,18 @@ impl Component for Model {
                             checked=true
                             label="Radio, checked (Same name 'radio-name' to create a group)"
                         />
+                        <FormRadioGroup
+                            name="radio-group"
+                            class="mb-3"
+                            label="Radio group, inline"
+                            inline=true
+                            options={ vec![
+                                RadioOption::new("1", "Option 1"),
+                                RadioOption::new("2", "Option 2"),
+                                RadioOption::new("3", "Option 3 (disabled)").disabled(),
+                            ] }
+                            value={ AttrValue::from("1") }
+                        />
                         <FormControl
                             id="input-date1"
                             ctype={ FormControlType::Date }
@@ -331,7 +349,7 @@ impl Component for Model {
                         <FormControl id="input-hidden" ctype={ FormControlType::Hidden } class="mb-3" label="Hidden input" />
                     </Container>
                     <h2>{ "Help, placeholder, disabled" }</h2>
-                    <Container size={ContainerSize::ExtraLarge}>
+                    <Container size={Breakpoint::Xl}>
                         <FormControl
                             id="input-text-help"
                             ctype={FormControlType::Text}
@@ -363,7 +381,7 @@ impl Component for Model {
                         />
                     </Container>
                     <h2>{ "Floating fields " }</h2>
-                    <Container size={ContainerSize::ExtraLarge}>
+                    <Container size={Breakpoint::Xl}>
                         <p>{
                             "Important: with floating set, label is required and placeholder is ignored. Not all field types are compatible."
                         }</p>
@@ -447,7 +465,7 @@ impl Component for Model {
                     <p>{
                         "Note: look at the console in Developper mode to see the callbacks and messages."
                     }</p>
-                    <Container size={ContainerSize::ExtraLarge}>
+                    <Container size={Breakpoint::Xl}>
                         <p>{ "Change the value of the following control. Validation is computed on the provided input
                               when typing ('oninput' instead of 'onchange'), verifies if the value is a valid i32
                               and in the correct range" }</p>
@@ -486,7 +504,7 @@ impl Component for Model {
                     <p>{
                         "Note: look at the console in Developper mode to see the callbacks and messages."
                     }</p>
-                    <Container size={ContainerSize::ExtraLarge}>
+                    <Container size={Breakpoint::Xl}>
                         <FormControl
                             id="input-text-callback"
                             name="input-text-callback"
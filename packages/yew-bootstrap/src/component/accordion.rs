@@ -1,6 +1,11 @@
+use std::collections::HashSet;
 use std::rc::Rc;
 
+use log::warn;
 use yew::prelude::*;
+use yew::virtual_dom::Key;
+
+use super::Collapse;
 
 /// # Properties of [AccordionHeader]
 #[derive(Properties, Clone, PartialEq)]
@@ -23,7 +28,11 @@ struct AccordionHeaderProps {
 
     /// If the associated accordion collapse is open
     #[prop_or_default]
-    expanded: bool
+    expanded: bool,
+
+    /// Called when the button is clicked
+    #[prop_or_default]
+    onclick: Callback<MouseEvent>,
 }
 
 /// # Accordion Header
@@ -40,10 +49,9 @@ fn AccordionHeader(props: &AccordionHeaderProps) -> Html {
             <button
                 class={props.button_classes.clone()} 
                 type="button" 
-                data-bs-toggle="collapse" 
-                data-bs-target={format!("#{}", props.collapse_id)} 
                 aria-expanded={props.expanded.to_string()} 
                 aria-controls={props.collapse_id.clone()}
+                onclick={props.onclick.clone()}
             >
                 { props.title.clone() }
             </button>
@@ -51,57 +59,6 @@ fn AccordionHeader(props: &AccordionHeaderProps) -> Html {
     }
 }
 
-/// # Properties of [AccordionCollapse]
-#[derive(Properties, Clone, PartialEq)]
-struct AccordionCollapseProps {
-    /// Parent [Accordion] html id attribute
-    #[prop_or(AttrValue::from("main-accordion"))]
-    parent_id: AttrValue,
-
-    /// Html id of this component
-    #[prop_or_default]
-    collapse_id: AttrValue,
-
-    /// Html id of associated header for this [AccordionItem]
-    #[prop_or_default]
-    heading_id: AttrValue,
-
-    /// Opening this item will close other items in the [Accordion]
-    #[prop_or_default]
-    stay_open: bool,
-
-    /// Classes attached to the div
-    #[prop_or_default]
-    class: Classes,
-
-    /// Inner components
-    #[prop_or_default]
-    children: Children,
-}
-
-/// # Accordion Collapse
-/// Used with [crate::component::AccordionItem] to create accordion drop downs
-/// This represents the body of the accordion item that can be opened/closed
-/// 
-/// See [AccordionCollapseProps] for a listing of properties
-///
-/// This component is not meant to be used stand-alone as it's only rendered inside of Accordions
-#[function_component]
-fn AccordionCollapse(props: &AccordionCollapseProps) -> Html {
-    if props.stay_open {
-        return html! {
-            <div id={props.collapse_id.clone()} class={props.class.clone()} aria-labelledby={props.heading_id.clone()}>
-                { for props.children.iter() }
-            </div>
-        }
-    }
-    html! {
-        <div id={props.collapse_id.clone()} class={props.class.clone()} aria-labelledby={props.heading_id.clone()} data-bs-parent={format!("#{}", props.parent_id)}>
-            { for props.children.iter() }
-        </div>
-    }
-}
-
 /// # Properties of [AccordionItem]
 #[derive(Properties, Clone, PartialEq)]
 pub struct AccordionItemProps {
@@ -109,17 +66,30 @@ pub struct AccordionItemProps {
     #[prop_or_default]
     pub title: AttrValue,
 
-    /// Item is currently open
+    /// Item is open when the [Accordion] is first rendered. Ignored, with a warning, in a
+    /// controlled [Accordion]: `active_key` takes precedence and opens the matching item.
     #[prop_or_default]
     pub expanded: bool,
 
-    /// Inner components (displayed in the [AccordionCollapse])
+    /// Inner components (displayed in the [Collapse])
     #[prop_or_default]
     pub children: Children,
 
-    /// Opening this item doesn't close other items
+    /// Called when the item has finished opening (end of the collapse transition)
+    #[prop_or_default]
+    pub on_shown: Callback<()>,
+
+    /// Called when the item has finished closing (end of the collapse transition)
+    #[prop_or_default]
+    pub on_hidden: Callback<()>,
+
+    /// Only mount the inner components when the item is opened for the first time
+    #[prop_or_default]
+    pub lazy: bool,
+
+    /// Unmount the inner components when the item is closed. Implies `lazy`.
     #[prop_or_default]
-    stay_open: bool,
+    pub unmount_on_collapse: bool,
 
     /// Html id attribute of parent [Accordion]
     #[prop_or(AttrValue::from("main-accordion"))]
@@ -128,39 +98,90 @@ pub struct AccordionItemProps {
     /// Position in the parent [Accordion]
     #[prop_or_default]
     item_id: usize,
+
+    /// Set by the parent [Accordion], toggles this item
+    #[prop_or_default]
+    on_toggle: Callback<MouseEvent>,
 }
 
 /// # A singular accordion item, child of [Accordion]
 /// Used as a child of [Accordion] to create an accordion menu.
 /// 
 /// Child components will be displayed in the body of the accordion item
+///
+/// `on_shown` and `on_hidden` are called when the collapse transition completes, for example to load the content of an item once it is open:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Accordion, AccordionItem};
+/// fn test() -> Html {
+///     html!{
+///         <Accordion>
+///             <AccordionItem
+///                 title={"Heading 1"}
+///                 on_shown={Callback::from(|_| log::info!("Item opened"))}
+///                 on_hidden={Callback::from(|_| log::info!("Item closed"))}
+///             >
+///                 {"Body"}
+///             </AccordionItem>
+///         </Accordion>
+///     }
+/// }
+/// ```
+///
+/// Items with heavy content can be mounted when first opened with `lazy`, and
+/// unmounted again when closed with `unmount_on_collapse`:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Accordion, AccordionItem};
+/// fn test() -> Html {
+///     html!{
+///         <Accordion>
+///             <AccordionItem title={"Statistics"} lazy={true}>
+///                 {"Expensive content"}
+///             </AccordionItem>
+///             <AccordionItem title={"Live feed"} unmount_on_collapse={true}>
+///                 {"Content that should not stay mounted"}
+///             </AccordionItem>
+///         </Accordion>
+///     }
+/// }
+/// ```
 #[function_component]
 pub fn AccordionItem(props: &AccordionItemProps) -> Html {
     let heading_id = format!("{}-heading-{}", props.parent_id, props.item_id);
     let collapse_id = format!("{}-collapse-{}", props.parent_id, props.item_id);
 
     let mut button_classes = classes!("accordion-button");
-    let mut collapse_classes = classes!("accordion-collapse",  "collapse");
-
-    // TODO: Maybe hook up the `expanded` property to some state depending on `stay_open`
-    //
-    // I think in the bootstrap docs this is really only meant to show one item as expanded after loading the page
-    // However as it currently is, users may be able to set this on multiple items at once
-    // This is probably fine during initial page load since they can be closed individually
-    // But it acts weird if an end-user were to open another item as it would close all of them unless `stay_open` is true
-    // 
-    // Additionally if some other part of the page is setup to use state to open an item
-    // This will cause 2 items to be open at once even if the `stay_open` flag is false
-    // There's no real harm putting the closing of accordion items on the user, but it would be nice if there were
-    // some sort of built in way to handle this
-    //
-    // I use ssr in my project so ideally this would also not interfere with rendering server side
     if !props.expanded {
         button_classes.push("collapsed");
-    } else {
-        collapse_classes.push("show");
     }
 
+    // Whether the inner components are mounted, only used with `lazy` or `unmount_on_collapse`
+    let mounted = use_state(|| props.expanded);
+    {
+        let mounted = mounted.clone();
+        use_effect_with(props.expanded, move |expanded| {
+            if *expanded {
+                mounted.set(true);
+            }
+        });
+    }
+    let render_body = !(props.lazy || props.unmount_on_collapse) || props.expanded || *mounted;
+
+    let on_hidden = {
+        let mounted = mounted.clone();
+        let unmount_on_collapse = props.unmount_on_collapse;
+        let on_hidden = props.on_hidden.clone();
+        Callback::from(move |_| {
+            if unmount_on_collapse {
+                mounted.set(false);
+            }
+            on_hidden.emit(());
+        })
+    };
+
     html! {
         <div class="accordion-item">
             <AccordionHeader 
@@ -169,18 +190,22 @@ pub fn AccordionItem(props: &AccordionItemProps) -> Html {
                 button_classes={button_classes}
                 collapse_id={collapse_id.clone()}
                 expanded={props.expanded}
+                onclick={props.on_toggle.clone()}
             />
-            <AccordionCollapse
-                class={collapse_classes}
-                stay_open={props.stay_open}
-                heading_id={heading_id}
-                collapse_id={collapse_id.clone()}
-                parent_id={props.parent_id.clone()}
+            <Collapse
+                class="accordion-collapse"
+                open={props.expanded}
+                id={collapse_id}
+                labelledby={heading_id}
+                on_shown={props.on_shown.clone()}
+                on_hidden={on_hidden}
             >
                 <div class="accordion-body">
-                    { for props.children.iter() }
+                    if render_body {
+                        { for props.children.iter() }
+                    }
                 </div>
-            </AccordionCollapse>
+            </Collapse>
         </div>
     }
 }
@@ -203,6 +228,16 @@ pub struct AccordionProps {
     // The [AccordionItem] instances controlled by this accordion
     #[prop_or_default]
     pub children: ChildrenWithProps<AccordionItem>,
+
+    /// Key of the open item in a controlled accordion. Items without a key use their position.
+    #[prop_or_default]
+    pub active_key: Option<Key>,
+
+    /// If set, the accordion is controlled: only the item matching `active_key` is open,
+    /// and clicking a header emits the key of the item to open (or `None` to close it)
+    /// instead of toggling it. `expanded` on the items is ignored.
+    #[prop_or_default]
+    pub on_change: Option<Callback<Option<Key>>>,
 }
 
 /// # Accordion
@@ -255,6 +290,28 @@ pub struct AccordionProps {
 ///     }
 /// }
 /// ```
+///
+/// Example of a controlled accordion, where the parent keeps track of the open item
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew::virtual_dom::Key;
+/// use yew_bootstrap::component::{Accordion, AccordionItem};
+/// #[function_component]
+/// fn Test() -> Html {
+///     let active = use_state(|| Some(Key::from("first")));
+///     let on_change = {
+///         let active = active.clone();
+///         Callback::from(move |key: Option<Key>| active.set(key))
+///     };
+///     html!{
+///         <Accordion active_key={(*active).clone()} on_change={on_change}>
+///             <AccordionItem key="first" title={"Heading 1"}>{"First body"}</AccordionItem>
+///             <AccordionItem key="second" title={"Heading 2"}>{"Second body"}</AccordionItem>
+///         </Accordion>
+///     }
+/// }
+/// ```
 #[function_component]
 pub fn Accordion(props: &AccordionProps) -> Html {
     let mut classes = classes!("accordian");
@@ -262,17 +319,83 @@ pub fn Accordion(props: &AccordionProps) -> Html {
         classes.push("accordion-flush");
     }
 
+    let item_key = |index: usize, child: &yew::virtual_dom::VChild<AccordionItem>| {
+        Html::from(child.clone()).key().cloned().unwrap_or_else(|| Key::from(index))
+    };
+
+    if props.on_change.is_some() && props.children.iter().any(|child| child.props.expanded) {
+        warn!("AccordionItem `expanded` is ignored in a controlled Accordion, use `active_key`");
+    }
+
+    // Keys of the open items, when the accordion is not controlled
+    let open = {
+        let children = props.children.clone();
+        use_state(move || {
+            children.iter().enumerate()
+                .filter(|(_, child)| child.props.expanded)
+                .map(|(index, child)| item_key(index, &child))
+                .collect::<HashSet<Key>>()
+        })
+    };
+
     html! {
         <div class={classes} id={props.id.clone()}>
             {
                 for props.children.iter().enumerate().map(|(index, mut child)| {
+                    let key = item_key(index, &child);
                     let child_props = Rc::make_mut(&mut child.props);
                     child_props.item_id = index;
                     child_props.parent_id = props.id.clone();
-                    child_props.stay_open = props.stay_open;
+                    if let Some(on_change) = &props.on_change {
+                        let expanded = props.active_key.as_ref() == Some(&key);
+                        child_props.expanded = expanded;
+                        child_props.on_toggle = on_change.reform(move |_: MouseEvent| {
+                            if expanded { None } else { Some(key.clone()) }
+                        });
+                    } else {
+                        let expanded = open.contains(&key);
+                        child_props.expanded = expanded;
+                        let open = open.clone();
+                        let stay_open = props.stay_open;
+                        child_props.on_toggle = Callback::from(move |_: MouseEvent| {
+                            let mut keys = if stay_open { (*open).clone() } else { HashSet::new() };
+                            if expanded {
+                                keys.remove(&key);
+                            } else {
+                                keys.insert(key.clone());
+                            }
+                            open.set(keys);
+                        });
+                    }
                     child
                 })
             }
         </div>
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use yew::LocalServerRenderer;
+
+    use super::*;
+
+    #[function_component]
+    fn Controlled() -> Html {
+        html! {
+            <Accordion id="test" active_key={Some(Key::from(1))} on_change={Callback::noop()}>
+                <AccordionItem title="First" expanded=true>{ "first" }</AccordionItem>
+                <AccordionItem title="Second">{ "second" }</AccordionItem>
+            </Accordion>
+        }
+    }
+
+    #[tokio::test]
+    async fn controlled_ignores_expanded() {
+        let html = LocalServerRenderer::<Controlled>::new().hydratable(false).render().await;
+        assert!(html.contains(r#"aria-expanded="false" aria-controls="test-collapse-0""#));
+        assert!(html.contains(r#"aria-expanded="true" aria-controls="test-collapse-1""#));
+        assert!(html.contains(r#"id="test-collapse-0" aria-labelledby="test-heading-0" class="collapse accordion-collapse""#));
+        assert!(html.contains(r#"id="test-collapse-1" aria-labelledby="test-heading-1" class="collapse show accordion-collapse""#));
+    }
+}
@@ -1,5 +1,7 @@
+use gloo::timers::callback::Timeout;
 use yew::prelude::*;
 
+use crate::icons::BI;
 use crate::util::Color;
 
 /// # Alert component
@@ -20,7 +22,61 @@ use crate::util::Color;
 ///     }
 /// }
 /// ```
-pub struct Alert {}
+///
+/// An icon can be shown before the content, aligned to the top of the alert:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Alert;
+/// use yew_bootstrap::icons::BI;
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html!{
+///         <Alert style={Color::Warning} icon={BI::EXCLAMATION_TRIANGLE_FILL}>
+///             {"This is a warning with an icon"}
+///         </Alert>
+///     }
+/// }
+/// ```
+///
+/// With `dismissible`, a close button is shown. The alert hides itself when it is
+/// clicked, and calls `on_close` so the parent can also remove it:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Alert;
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html!{
+///         <Alert
+///             style={Color::Info}
+///             dismissible={true}
+///             on_close={Callback::from(|_| log::info!("Alert closed"))}
+///         >
+///             {"This alert can be closed"}
+///         </Alert>
+///     }
+/// }
+/// ```
+///
+/// An alert can also close itself after a delay with `auto_dismiss`, in milliseconds:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Alert;
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html!{
+///         <Alert style={Color::Success} dismissible={true} auto_dismiss={5000}>
+///             {"Saved"}
+///         </Alert>
+///     }
+/// }
+/// ```
+pub struct Alert {
+    /// False once the alert was closed
+    visible: bool,
+
+    /// Pending `auto_dismiss` timer, cancelled when dropped
+    timeout: Option<Timeout>,
+}
 
 /// # Properties of [Alert]
 #[derive(Properties, Clone, PartialEq)]
@@ -40,23 +96,99 @@ pub struct AlertProps {
     /// Optional text placed before the children
     #[prop_or_default]
     pub text: String,
+
+    /// Optional icon displayed before the content. The alert then uses a flex
+    /// layout, with the icon aligned to the top.
+    #[prop_or_default]
+    pub icon: Option<BI>,
+
+    /// Show a close button, which hides the alert and calls `on_close`
+    #[prop_or_default]
+    pub dismissible: bool,
+
+    /// Called when the close button is clicked
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+
+    /// If set, the alert is closed after this delay in milliseconds, calling
+    /// `on_close`. The delay starts again when this value changes.
+    #[prop_or_default]
+    pub auto_dismiss: Option<u32>,
+}
+
+impl Alert {
+    fn start_timeout(ctx: &Context<Self>) -> Option<Timeout> {
+        let link = ctx.link().clone();
+        ctx.props().auto_dismiss.map(|delay| Timeout::new(delay, move || link.send_message(())))
+    }
 }
 
 impl Component for Alert {
+    /// Close button clicked, or `auto_dismiss` delay elapsed
     type Message = ();
     type Properties = AlertProps;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {}
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            visible: true,
+            timeout: Self::start_timeout(ctx),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, _msg: ()) -> bool {
+        if !self.visible {
+            return false;
+        }
+        self.visible = false;
+        self.timeout = None;
+        ctx.props().on_close.emit(());
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if self.visible && ctx.props().auto_dismiss != old_props.auto_dismiss {
+            self.timeout = Self::start_timeout(ctx);
+        }
+        true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
+        if !self.visible {
+            return html! {};
+        }
+
         let mut classes = Classes::new();
         classes.push("alert");
         classes.push(format!("alert-{}", props.style));
+        if props.icon.is_some() {
+            classes.push("d-flex align-items-start");
+        }
+        if props.dismissible {
+            classes.push("alert-dismissible fade show");
+        }
         classes.push(props.class.clone());
 
+        let close = props.dismissible.then(|| html! {
+            <button type="button" class="btn-close" aria-label="Close" onclick={ctx.link().callback(|_| ())}></button>
+        });
+
+        if let Some(icon) = props.icon {
+            return html! {
+                <div
+                    class={classes}
+                    role="alert"
+                >
+                    <div class="flex-shrink-0 me-2" aria-hidden="true">{ icon }</div>
+                    <div class="flex-grow-1">
+                        { &props.text }
+                        { for props.children.iter() }
+                    </div>
+                    { close }
+                </div>
+            }
+        }
+
         html! {
             <div
                 class={classes}
@@ -64,6 +196,7 @@ impl Component for Alert {
             >
                 { &props.text }
                 { for props.children.iter() }
+                { close }
             </div>
         }
     }
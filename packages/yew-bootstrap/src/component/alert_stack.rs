@@ -0,0 +1,185 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::Alert;
+use crate::util::Color;
+
+/// Alert displayed in an [AlertStack]
+#[derive(Clone, PartialEq)]
+pub struct StackedAlert {
+    /// Identifier of the alert in the stack
+    pub id: usize,
+    /// Color style of the alert
+    pub style: Color,
+    /// Message of the alert
+    pub text: AttrValue,
+    /// If set, the alert is dismissed after this delay, in milliseconds
+    pub auto_dismiss: Option<u32>,
+}
+
+/// Alerts of an [AlertStack] managed by [use_alert_stack]
+#[derive(Clone, Default, PartialEq)]
+pub struct AlertStackState {
+    alerts: Vec<StackedAlert>,
+    next_id: usize,
+}
+
+/// Change applied to [AlertStackState] by [UseAlertStackHandle]
+pub enum AlertStackAction {
+    /// Add an alert at the end of the stack
+    Push {
+        /// Color style of the alert
+        style: Color,
+        /// Message of the alert
+        text: AttrValue,
+        /// Optional delay before the alert is dismissed, in milliseconds
+        auto_dismiss: Option<u32>,
+    },
+    /// Remove the alert with this id
+    Dismiss(usize),
+    /// Remove all the alerts
+    Clear,
+}
+
+impl Reducible for AlertStackState {
+    type Action = AlertStackAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut state = (*self).clone();
+        match action {
+            AlertStackAction::Push { style, text, auto_dismiss } => {
+                state.alerts.push(StackedAlert { id: state.next_id, style, text, auto_dismiss });
+                state.next_id += 1;
+            }
+            AlertStackAction::Dismiss(id) => state.alerts.retain(|alert| alert.id != id),
+            AlertStackAction::Clear => state.alerts.clear(),
+        }
+        Rc::new(state)
+    }
+}
+
+/// Handle returned by [use_alert_stack]
+#[derive(Clone)]
+pub struct UseAlertStackHandle {
+    state: UseReducerHandle<AlertStackState>,
+}
+
+impl UseAlertStackHandle {
+    /// Add an alert to the stack
+    pub fn push(&self, style: Color, text: impl Into<AttrValue>) {
+        self.state.dispatch(AlertStackAction::Push { style, text: text.into(), auto_dismiss: None });
+    }
+
+    /// Add an alert to the stack, dismissed after `delay` milliseconds
+    pub fn push_timed(&self, style: Color, text: impl Into<AttrValue>, delay: u32) {
+        self.state.dispatch(AlertStackAction::Push { style, text: text.into(), auto_dismiss: Some(delay) });
+    }
+
+    /// Remove the alert with this id
+    pub fn dismiss(&self, id: usize) {
+        self.state.dispatch(AlertStackAction::Dismiss(id));
+    }
+
+    /// Remove all the alerts
+    pub fn clear(&self) {
+        self.state.dispatch(AlertStackAction::Clear);
+    }
+
+    /// Alerts currently in the stack, to pass as `alerts` to [AlertStack]
+    pub fn alerts(&self) -> Vec<StackedAlert> {
+        self.state.alerts.clone()
+    }
+
+    /// Callback removing an alert, to pass as `on_dismiss` to [AlertStack]
+    pub fn on_dismiss(&self) -> Callback<usize> {
+        let state = self.state.clone();
+        Callback::from(move |id| state.dispatch(AlertStackAction::Dismiss(id)))
+    }
+}
+
+/// # Alert stack hook
+/// Manages the list of alerts displayed by an [AlertStack]. See [AlertStack] for an example.
+#[hook]
+pub fn use_alert_stack() -> UseAlertStackHandle {
+    UseAlertStackHandle { state: use_reducer(AlertStackState::default) }
+}
+
+/// # Properties of [AlertStack]
+#[derive(Properties, Clone, PartialEq)]
+pub struct AlertStackProps {
+    /// Alerts to display, in order
+    #[prop_or_default]
+    pub alerts: Vec<StackedAlert>,
+
+    /// Called with the id of an alert closed by the user or dismissed automatically
+    #[prop_or_default]
+    pub on_dismiss: Callback<usize>,
+
+    /// CSS class
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Properties of [StackedAlertItem]
+#[derive(Properties, Clone, PartialEq)]
+struct StackedAlertItemProps {
+    alert: StackedAlert,
+    on_dismiss: Callback<usize>,
+}
+
+/// Alert of an [AlertStack], with a close button and optional timer
+#[function_component]
+fn StackedAlertItem(props: &StackedAlertItemProps) -> Html {
+    let StackedAlert { id, style, text, auto_dismiss } = props.alert.clone();
+    let on_close = props.on_dismiss.reform(move |_| id);
+
+    html! {
+        <Alert style={style} class="mb-0" dismissible={true} auto_dismiss={auto_dismiss} on_close={on_close}>
+            { text }
+        </Alert>
+    }
+}
+
+/// # Alert stack
+/// Area displaying several alerts stacked with consistent spacing. Each alert can be
+/// closed by the user, or dismissed automatically after a delay.
+///
+/// The alerts are usually managed with [use_alert_stack]. The stack is an `aria-live`
+/// region, so new alerts are announced by screen readers.
+///
+/// See [AlertStackProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{use_alert_stack, AlertStack, Button};
+/// use yew_bootstrap::util::Color;
+/// #[function_component]
+/// fn Messages() -> Html {
+///     let stack = use_alert_stack();
+///     let onclick = {
+///         let stack = stack.clone();
+///         Callback::from(move |_| stack.push_timed(Color::Success, "Saved", 5000))
+///     };
+///     html!{
+///         <>
+///             <Button onclick={onclick} text="Save" />
+///             <AlertStack alerts={stack.alerts()} on_dismiss={stack.on_dismiss()} class="mt-3" />
+///         </>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn AlertStack(props: &AlertStackProps) -> Html {
+    let mut classes = classes!("d-flex", "flex-column", "gap-2");
+    classes.push(props.class.clone());
+
+    html! {
+        <div class={classes} aria-live="polite">
+            { for props.alerts.iter().map(|alert| html! {
+                <StackedAlertItem key={alert.id} alert={alert.clone()} on_dismiss={props.on_dismiss.clone()} />
+            }) }
+        </div>
+    }
+}
@@ -1,6 +1,6 @@
 use yew::prelude::*;
 
-use crate::util::{Color, ArrangeX, ArrangeY};
+use crate::util::{Color, ArrangeX, ArrangeY, Placement, Position};
 
 /// # Badge component
 /// Used alongside [crate::util::Color] to create Badge components
@@ -20,6 +20,19 @@ use crate::util::{Color, ArrangeX, ArrangeY};
 ///     }
 /// }
 /// ```
+///
+/// A badge conveying a status by its color alone should have a `sr_label`,
+/// read by screen readers only:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Badge;
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html!{
+///         <Badge style={Color::Success} pill={true} sr_label="Online">{" "}</Badge>
+///     }
+/// }
+/// ```
 pub struct Badge {}
 
 /// # Properties of [Badge]
@@ -48,6 +61,11 @@ pub struct BadgeProps {
     /// Optional text placed before the children
     #[prop_or_default]
     pub text: String,
+
+    /// Optional text only read by screen readers, for badges conveying a status
+    /// by their color alone
+    #[prop_or_default]
+    pub sr_label: Option<AttrValue>,
 }
 
 impl Component for Badge {
@@ -61,21 +79,15 @@ impl Component for Badge {
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
         let mut classes = Classes::new();
-        match &props.position {
-            Some(position) => {
-                classes.push("position-absolute".to_string());
-                classes.push(format!("{}", position.0));
-                classes.push(format!("{}", position.1));
-                classes.push("translate-middle".to_string());
-            }
-            None => {}
+        if let Some((x, y)) = &props.position {
+            classes.push(Placement::new(Position::Absolute).x(x.clone()).y(y.clone()).translate_middle());
         }
         classes.push("badge");
         if props.pill {
             classes.push("rounded-pill");
         }
         classes.push(format!("bg-{}", props.style));
-        if [Color::Warning, Color::Info, Color::Light].contains(&props.style) {
+        if props.style.contrasting_text() == Some(Color::Dark) {
             classes.push("text-dark");
         }
         classes.push(props.class.clone());
@@ -86,6 +98,9 @@ impl Component for Badge {
             >
                 { &props.text }
                 { for props.children.iter() }
+                if let Some(sr_label) = &props.sr_label {
+                    <span class="visually-hidden">{ sr_label.clone() }</span>
+                }
             </span>
         }
     }
@@ -0,0 +1,94 @@
+use yew::prelude::*;
+
+/// # Properties of [Breadcrumb]
+#[derive(Properties, Clone, PartialEq)]
+pub struct BreadcrumbProps {
+    /// Inner items (displayed in the [Breadcrumb])
+    #[prop_or_default]
+    pub children: ChildrenWithProps<BreadcrumbItem>,
+    /// Extra CSS classes of the list
+    #[prop_or_default]
+    pub class: Classes,
+    /// Accessible label of the navigation, default "breadcrumb"
+    #[prop_or(AttrValue::from("breadcrumb"))]
+    pub aria_label: AttrValue,
+}
+
+/// # Breadcrumb component
+/// Location of the current page in the navigation hierarchy, made of [BreadcrumbItem]s.
+///
+/// See [BreadcrumbProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Breadcrumb, BreadcrumbItem};
+/// fn test() -> Html {
+///     html! {
+///         <Breadcrumb>
+///             <BreadcrumbItem href="/">{"Home"}</BreadcrumbItem>
+///             <BreadcrumbItem href="/library">{"Library"}</BreadcrumbItem>
+///             <BreadcrumbItem active=true>{"Data"}</BreadcrumbItem>
+///         </Breadcrumb>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Breadcrumb(props: &BreadcrumbProps) -> Html {
+    let mut classes = classes!("breadcrumb");
+    classes.extend(&props.class);
+
+    html! {
+        <nav aria-label={props.aria_label.clone()}>
+            <ol class={classes}>
+                { for props.children.iter() }
+            </ol>
+        </nav>
+    }
+}
+
+/// # Properties for [BreadcrumbItem]
+#[derive(Properties, Clone, PartialEq)]
+pub struct BreadcrumbItemProps {
+    /// Inner components, typically the page name
+    #[prop_or_default]
+    pub children: Children,
+    /// Extra CSS classes to include, in addition to the defaults
+    #[prop_or_default]
+    pub class: Classes,
+    /// Whether this item is the current page. The item is then not a link.
+    #[prop_or_default]
+    pub active: bool,
+    /// URL of the page, ignored for the active item
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    /// Event called when the link is clicked
+    #[prop_or_default]
+    pub onclick: Callback<MouseEvent>,
+}
+
+/// # BreadcrumbItem
+/// Page of a [Breadcrumb], a link unless it is the `active` (current) page.
+///
+/// See [BreadcrumbItemProps] for a list of properties, and [Breadcrumb] for an example.
+#[function_component]
+pub fn BreadcrumbItem(props: &BreadcrumbItemProps) -> Html {
+    let mut classes = classes!("breadcrumb-item");
+    if props.active {
+        classes.push("active");
+    }
+    classes.extend(&props.class);
+
+    match &props.href {
+        Some(href) if !props.active => html! {
+            <li class={classes}>
+                <a href={href.clone()} onclick={props.onclick.clone()}>{ props.children.clone() }</a>
+            </li>
+        },
+        _ => html! {
+            <li class={classes} aria-current={props.active.then_some("page")}>
+                { props.children.clone() }
+            </li>
+        },
+    }
+}
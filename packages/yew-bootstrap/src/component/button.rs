@@ -1,16 +1,40 @@
-use crate::util::Color;
+use std::rc::Rc;
+
+use crate::util::{prefers_reduced_motion, Color};
+use super::Badge;
+use web_sys::HtmlElement;
 use yew::prelude::*;
+use yew::virtual_dom::VChild;
+#[cfg(debug_assertions)]
+use yew::virtual_dom::VNode;
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Default, PartialEq, Eq)]
 pub enum ButtonSize {
     Large,
+    #[default]
     Normal,
     Small,
 }
 
-impl Default for ButtonSize {
-    fn default() -> Self {
-        ButtonSize::Normal
+/// Value of the `type` attribute of a [Button]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ButtonType {
+    /// Submits the form it belongs to
+    Submit,
+    /// Resets the form it belongs to
+    Reset,
+    /// Does nothing on its own, only calls `onclick`
+    #[default]
+    Button,
+}
+
+impl std::fmt::Display for ButtonType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ButtonType::Submit => write!(f, "submit"),
+            ButtonType::Reset => write!(f, "reset"),
+            ButtonType::Button => write!(f, "button"),
+        }
     }
 }
 
@@ -57,7 +81,161 @@ impl Default for ButtonSize {
 ///     }
 /// }
 /// ```
-pub struct Button {}
+///
+/// A button that only contains an icon needs an `aria_label`, otherwise it is
+/// announced without any name by assistive technologies:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Button;
+/// use yew_bootstrap::icons::BI;
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html ! {
+///         <>
+///             <Button style={Color::Danger} aria_label="Delete">{BI::TRASH}</Button>
+///             <Button style={Color::Primary} aria_label="Settings" hidden_label={true}>
+///                 {BI::GEAR}
+///             </Button>
+///         </>
+///     }
+/// }
+/// ```
+///
+/// Buttons have `type="button"` by default, so they do not submit the form they
+/// are in. Use `button_type` to make a submit or reset button:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Button, ButtonType};
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html ! {
+///         <form>
+///             <Button style={Color::Secondary} text="Preview" />
+///             <Button style={Color::Secondary} button_type={ButtonType::Reset} text="Reset" />
+///             <Button style={Color::Primary} button_type={ButtonType::Submit} text="Send" />
+///         </form>
+///     }
+/// }
+/// ```
+///
+/// With `name` and `value`, the submitted form tells which button was used:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Button, ButtonType};
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html ! {
+///         <form action="/review" method="post">
+///             <Button style={Color::Success} button_type={ButtonType::Submit} name="decision" value="approve" text="Approve" />
+///             <Button style={Color::Danger} button_type={ButtonType::Submit} name="decision" value="reject" text="Reject" />
+///         </form>
+///     }
+/// }
+/// ```
+///
+/// A button can submit a form it is not part of, or override the form's action:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Button, ButtonType};
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html ! {
+///         <>
+///             <form id="ExampleForm" action="/save" method="post"></form>
+///             <Button style={Color::Primary} button_type={ButtonType::Submit} form="ExampleForm" text="Save" />
+///             <Button
+///                 style={Color::Secondary}
+///                 button_type={ButtonType::Submit}
+///                 form="ExampleForm"
+///                 formaction="/draft"
+///                 formnovalidate={true}
+///                 text="Save draft"
+///             />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// Buttons can give visual feedback while they are pressed, useful on touch screens:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Button;
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html ! {
+///         <Button style={Color::Primary} press_feedback={true} text="Tap me" />
+///     }
+/// }
+/// ```
+///
+/// While `loading` is set, the button is disabled and shows a small spinner, for
+/// example while a form is submitted:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Button;
+/// use yew_bootstrap::util::Color;
+/// #[function_component]
+/// fn Save() -> Html {
+///     let saving = use_state(|| false);
+///     let onclick = {
+///         let saving = saving.clone();
+///         Callback::from(move |_| saving.set(true))
+///     };
+///     html ! {
+///         <Button
+///             style={Color::Primary}
+///             text="Save"
+///             loading={*saving}
+///             loading_text="Saving..."
+///             onclick={onclick}
+///         />
+///     }
+/// }
+/// ```
+///
+/// A [crate::component::Badge] can be added after the text, or as a positioned
+/// notification counter when the badge has a `position`:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Badge, Button};
+/// use yew_bootstrap::util::{ArrangeX, ArrangeY, Color};
+/// fn test() -> Html {
+///     html ! {
+///         <>
+///             <Button
+///                 style={Color::Primary}
+///                 text="Notifications"
+///                 badge={html_nested!{ <Badge style={Color::Secondary}>{"4"}</Badge> }}
+///             />
+///             <Button
+///                 style={Color::Primary}
+///                 text="Inbox"
+///                 badge={html_nested!{
+///                     <Badge style={Color::Danger} pill={true} position={(ArrangeX::Start100, ArrangeY::Top0)}>
+///                         {"99+"}
+///                     </Badge>
+///                 }}
+///             />
+///         </>
+///     }
+/// }
+/// ```
+pub struct Button {
+    /// Button is currently pressed, only tracked with `press_feedback`
+    pressed: bool,
+
+    node_ref: NodeRef,
+
+    /// Width of the button when it started loading, kept as minimum width while loading
+    loading_width: Option<i32>,
+}
 
 /// # Properties for [Button]
 #[derive(Properties, Clone, PartialEq)]
@@ -74,6 +252,10 @@ pub struct ButtonProps {
     #[prop_or_default]
     pub block: bool,
 
+    /// Type of the button, default [ButtonType::Button] which does not submit forms
+    #[prop_or_default]
+    pub button_type: ButtonType,
+
     /// Status of the button. Disabled buttons cannot be clicked.
     #[prop_or_default]
     pub disabled: bool,
@@ -82,6 +264,11 @@ pub struct ButtonProps {
     #[prop_or_default]
     pub name: String,
 
+    /// Value submitted with `name` when the form is submitted with this button, to tell
+    /// which button was used
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
+
     /// Event called when the button is clicked
     #[prop_or_default]
     pub onclick: Callback<MouseEvent>,
@@ -109,14 +296,96 @@ pub struct ButtonProps {
     /// true if this button dismisses the modal that contains it
     #[prop_or_default]
     pub modal_dismiss: bool,
+
+    /// Accessible label, required when the button only contains an icon
+    #[prop_or_default]
+    pub aria_label: Option<AttrValue>,
+
+    /// If true, `aria_label` is rendered as a `.visually-hidden` span inside the
+    /// button instead of an `aria-label` attribute
+    #[prop_or_default]
+    pub hidden_label: bool,
+
+    /// Optional badge displayed after the text. If the badge has a `position`, the
+    /// button is made `position-relative` so the badge is placed relative to it.
+    #[prop_or_default]
+    pub badge: Option<VChild<Badge>>,
+
+    /// Id of the `<form>` this button belongs to, if it is not inside it
+    #[prop_or_default]
+    pub form: Option<AttrValue>,
+
+    /// URL the form is submitted to when using this button, overriding the form `action`
+    #[prop_or_default]
+    pub formaction: Option<AttrValue>,
+
+    /// HTTP method used when submitting the form with this button, overriding the form `method`
+    #[prop_or_default]
+    pub formmethod: Option<AttrValue>,
+
+    /// If true, the form is not validated when submitted with this button
+    #[prop_or_default]
+    pub formnovalidate: bool,
+
+    /// If true, the button shrinks slightly while pressed. Disabled when the user
+    /// prefers reduced motion.
+    #[prop_or_default]
+    pub press_feedback: bool,
+
+    /// If true, the button is disabled and shows a small spinner before its content
+    #[prop_or_default]
+    pub loading: bool,
+
+    /// Replaces `text` and the children while `loading`
+    #[prop_or_default]
+    pub loading_text: Option<AttrValue>,
+}
+
+/// True if the node contains text. Components are opaque, and counted as icons.
+#[cfg(debug_assertions)]
+fn has_text(node: &VNode) -> bool {
+    match node {
+        VNode::VText(text) => !text.text.trim().is_empty(),
+        VNode::VTag(tag) => tag.children().is_some_and(has_text),
+        VNode::VList(list) => list.iter().any(has_text),
+        _ => false,
+    }
+}
+
+/// True if the button has content, but no text: only icons, as raw html, `<i>` or components
+#[cfg(debug_assertions)]
+fn is_icon_only(props: &ButtonProps) -> bool {
+    props.text.is_empty() && !props.children.is_empty() && !props.children.iter().any(|child| has_text(&child))
 }
 
 impl Component for Button {
-    type Message = ();
+    /// New pressed state
+    type Message = bool;
     type Properties = ButtonProps;
 
     fn create(_ctx: &Context<Self>) -> Self {
-        Self {}
+        Self {
+            pressed: false,
+            node_ref: NodeRef::default(),
+            loading_width: None,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        // The button is not rendered again yet, so this is the width before loading
+        self.loading_width = match (old_props.loading, ctx.props().loading) {
+            (false, true) => self.node_ref.cast::<HtmlElement>()
+                .map(|button| button.offset_width()),
+            (_, false) => None,
+            (true, true) => self.loading_width,
+        };
+        true
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, pressed: bool) -> bool {
+        let changed = self.pressed != pressed;
+        self.pressed = pressed;
+        changed
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
@@ -136,6 +405,14 @@ impl Component for Button {
         if props.block {
             classes.push("btn-block");
         }
+        let badge = props.badge.clone().map(|mut badge| {
+            if badge.props.position.is_some() {
+                classes.push("position-relative");
+            } else {
+                Rc::make_mut(&mut badge.props).class.push_str(" ms-1");
+            }
+            badge
+        });
         classes.push(props.class.clone());
 
         let modal_dismiss = match props.modal_dismiss {
@@ -143,34 +420,182 @@ impl Component for Button {
             false => "",
         };
 
+        #[cfg(debug_assertions)]
+        if props.aria_label.is_none() && is_icon_only(props) {
+            log::warn!("Button has no text and no `aria_label`, it will not be accessible to screen readers.");
+        }
+
+        let disabled = props.disabled || props.loading;
+        let mut css = Vec::new();
+        if let Some(width) = self.loading_width {
+            css.push(format!("min-width: {}px", width));
+        }
+
+        let press_feedback = props.press_feedback && !disabled && !prefers_reduced_motion();
+        let (onpointerdown, onpointerup, onpointerleave) = if press_feedback {
+            css.push("transition: transform 0.1s ease-in-out".to_string());
+            if self.pressed {
+                css.push("transform: scale(0.96)".to_string());
+            }
+            let link = ctx.link();
+            (
+                Some(link.callback(|_: PointerEvent| true)),
+                Some(link.callback(|_: PointerEvent| false)),
+                Some(link.callback(|_: PointerEvent| false)),
+            )
+        } else {
+            (None, None, None)
+        };
+        let style = (!css.is_empty()).then(|| css.join("; "));
+
+        let content = match (props.loading, &props.loading_text) {
+            (true, Some(loading_text)) => html! {
+                <>
+                    <span class="spinner-border spinner-border-sm me-1" aria-hidden="true"></span>
+                    { loading_text.clone() }
+                </>
+            },
+            (loading, _) => html! {
+                <>
+                    if loading {
+                        <span class="spinner-border spinner-border-sm me-1" aria-hidden="true"></span>
+                    }
+                    { &props.text }
+                    { for props.children.iter() }
+                </>
+            },
+        };
+        let aria_busy = props.loading.then_some("true");
+
+        let (aria_label, hidden_label) = match (&props.aria_label, props.hidden_label) {
+            (Some(label), true) => (None, Some(html! {
+                <span class="visually-hidden">{ label.clone() }</span>
+            })),
+            (label, _) => (label.clone(), None),
+        };
+
         if let Some(target) = &props.modal_target {
             html! {
                 <button
+                    ref={self.node_ref.clone()}
+                    type={props.button_type.to_string()}
                     class={classes}
-                    disabled={props.disabled}
+                    disabled={disabled}
+                    aria-busy={aria_busy}
                     name={props.name.clone()}
+                    value={props.value.clone()}
                     onclick={props.onclick.clone()}
                     data-bs-toggle="modal"
                     data-bs-target={format!("#{}",target.clone())}
+                    aria-label={aria_label}
+                    form={props.form.clone()}
+                    formaction={props.formaction.clone()}
+                    formmethod={props.formmethod.clone()}
+                    formnovalidate={props.formnovalidate}
+                    style={style.clone()}
+                    onpointerdown={onpointerdown.clone()}
+                    onpointerup={onpointerup.clone()}
+                    onpointerleave={onpointerleave.clone()}
                 >
-                    { &props.text }
-                    { for props.children.iter() }
+                    { content }
+                    { badge }
+                    { hidden_label }
                 </button>
             }
         } else {
             html! {
                 <button
+                    ref={self.node_ref.clone()}
+                    type={props.button_type.to_string()}
                     class={classes}
-                    disabled={props.disabled}
+                    disabled={disabled}
+                    aria-busy={aria_busy}
                     name={props.name.clone()}
+                    value={props.value.clone()}
                     onclick={props.onclick.clone()}
                     data-bs-dismiss={modal_dismiss}
+                    aria-label={aria_label}
+                    form={props.form.clone()}
+                    formaction={props.formaction.clone()}
+                    formmethod={props.formmethod.clone()}
+                    formnovalidate={props.formnovalidate}
+                    style={style.clone()}
+                    onpointerdown={onpointerdown.clone()}
+                    onpointerup={onpointerup.clone()}
+                    onpointerleave={onpointerleave.clone()}
                 >
-                    { &props.text }
-                    { for props.children.iter() }
+                    { content }
+                    { badge }
+                    { hidden_label }
                 </button>
             }
         }
 
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use yew::LocalServerRenderer;
+
+    use super::*;
+
+    #[derive(Properties, PartialEq)]
+    struct TestProps {
+        button_type: Option<ButtonType>,
+    }
+
+    #[function_component]
+    fn TestForm(props: &TestProps) -> Html {
+        html! {
+            <form>
+                if let Some(button_type) = props.button_type {
+                    <Button text="Send" button_type={button_type} />
+                } else {
+                    <Button text="Send" />
+                }
+            </form>
+        }
+    }
+
+    async fn render(button_type: Option<ButtonType>) -> String {
+        LocalServerRenderer::<TestForm>::with_props(TestProps { button_type })
+            .hydratable(false)
+            .render()
+            .await
+    }
+
+    // A `<button>` without a `type` submits its form, one with `type="button"` never does
+    #[tokio::test]
+    async fn plain_button_does_not_submit() {
+        let html = render(None).await;
+        assert!(html.contains(r#"<button type="button""#), "{}", html);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn icon_only() {
+        let button = |text: &str, children: Html| yew::props!(ButtonProps {
+            text: text.to_string(),
+            children: Children::new(vec![children]),
+        });
+        assert!(is_icon_only(&button("", html! { { crate::icons::BI::TRASH } })));
+        assert!(is_icon_only(&button("", html! { <i class="bi bi-trash"></i> })));
+        assert!(!is_icon_only(&button("Delete", html! { { crate::icons::BI::TRASH } })));
+        assert!(!is_icon_only(&button("", html! { <span>{ "Delete" }</span> })));
+        assert!(!is_icon_only(&yew::props!(ButtonProps {})));
+    }
+
+    #[tokio::test]
+    async fn button_type_attribute() {
+        let cases = [
+            (ButtonType::Submit, r#"type="submit""#),
+            (ButtonType::Reset, r#"type="reset""#),
+            (ButtonType::Button, r#"type="button""#),
+        ];
+        for (button_type, attribute) in cases {
+            let html = render(Some(button_type)).await;
+            assert!(html.contains(attribute), "{}", html);
+        }
+    }
+}
@@ -22,6 +22,10 @@ use yew::prelude::*;
 ///     }
 /// }
 /// ```
+///
+/// Groups are exposed with `role="group"`, and should be labelled, either with
+/// `label` or with the id of a visible label in `labelledby`. Several groups can be
+/// combined in a [ButtonToolbar].
 pub struct ButtonGroup {}
 
 /// Properties for [ButtonGroup]
@@ -39,8 +43,13 @@ pub struct ButtonGroupProps {
     #[prop_or_default]
     pub label: String,
 
-    /// Role, used for assistive technoligies to describe the purpose of the group.
+    /// Html id of the element labelling the group, used for assistive technologies
     #[prop_or_default]
+    pub labelledby: Option<AttrValue>,
+
+    /// Role, used for assistive technoligies to describe the purpose of the group.
+    /// Default `group`.
+    #[prop_or(String::from("group"))]
     pub role: String,
 
     /// If true, disposition is vertical (Default horizontal)
@@ -69,8 +78,83 @@ impl Component for ButtonGroup {
         html! {
             <div
                 class={classes}
-                role={props.role.clone()}
-                aria-label={props.label.clone()}
+                role={(!props.role.is_empty()).then(|| props.role.clone())}
+                aria-label={(!props.label.is_empty()).then(|| props.label.clone())}
+                aria-labelledby={props.labelledby.clone()}
+            >
+                { for props.children.iter() }
+            </div>
+        }
+    }
+}
+
+/// # Button toolbar
+/// [ButtonToolbar] combines several [ButtonGroup] instances, exposed to assistive
+/// technologies with `role="toolbar"`.
+///
+/// See [ButtonToolbarProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Button, ButtonGroup, ButtonToolbar};
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html!{
+///         <ButtonToolbar label="Editor toolbar">
+///             <ButtonGroup class="me-2" label="Formatting">
+///                 <Button style={Color::Secondary} text="Bold"/>
+///                 <Button style={Color::Secondary} text="Italic"/>
+///             </ButtonGroup>
+///             <ButtonGroup label="History">
+///                 <Button style={Color::Secondary} text="Undo"/>
+///             </ButtonGroup>
+///         </ButtonToolbar>
+///     }
+/// }
+/// ```
+pub struct ButtonToolbar {}
+
+/// Properties for [ButtonToolbar]
+#[derive(Properties, Clone, PartialEq)]
+pub struct ButtonToolbarProps {
+    /// CSS class
+    #[prop_or_default]
+    pub class: String,
+
+    /// Children for the toolbar ([ButtonGroup] instances)
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Aria label used for assistive technologies
+    #[prop_or_default]
+    pub label: String,
+
+    /// Html id of the element labelling the toolbar, used for assistive technologies
+    #[prop_or_default]
+    pub labelledby: Option<AttrValue>,
+}
+
+impl Component for ButtonToolbar {
+    type Message = ();
+    type Properties = ButtonToolbarProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {}
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let mut classes = Classes::new();
+        classes.push("btn-toolbar");
+        classes.push(props.class.clone());
+
+        html! {
+            <div
+                class={classes}
+                role="toolbar"
+                aria-label={(!props.label.is_empty()).then(|| props.label.clone())}
+                aria-labelledby={props.labelledby.clone()}
             >
                 { for props.children.iter() }
             </div>
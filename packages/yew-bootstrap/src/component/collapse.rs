@@ -0,0 +1,190 @@
+use gloo::timers::callback::Timeout;
+use web_sys::Element;
+use yew::prelude::*;
+
+/// Duration of the Bootstrap `.collapsing` transition, in milliseconds
+const TRANSITION_DURATION: u32 = 350;
+
+/// Phase of the collapse animation
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    Hidden,
+    Showing,
+    Shown,
+    Hiding,
+}
+
+/// State of a [Collapse]: animation phase, and inline height during the transition
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct CollapseState {
+    phase: Phase,
+    height: i32,
+}
+
+/// # Properties of [Collapse]
+#[derive(Properties, Clone, PartialEq)]
+pub struct CollapseProps {
+    /// Content is visible
+    #[prop_or_default]
+    pub open: bool,
+
+    /// Html id of the collapse, to be referenced by `aria-controls` on the toggle
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+
+    /// Html id of the element labelling the collapse
+    #[prop_or_default]
+    pub labelledby: Option<AttrValue>,
+
+    /// Classes attached to the collapse
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Called when the collapse has finished opening
+    #[prop_or_default]
+    pub on_shown: Callback<()>,
+
+    /// Called when the collapse has finished closing
+    #[prop_or_default]
+    pub on_hidden: Callback<()>,
+
+    /// Inner components
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// # Collapse
+/// Shows or hides its content with the Bootstrap height animation, without
+/// Bootstrap JS. The content is visible when `open` is true.
+///
+/// See [CollapseProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Button, Collapse};
+/// #[function_component]
+/// fn Details() -> Html {
+///     let open = use_state(|| false);
+///     let onclick = {
+///         let open = open.clone();
+///         Callback::from(move |_| open.set(!*open))
+///     };
+///     html!{
+///         <>
+///             <Button onclick={onclick} text="Toggle details" />
+///             <Collapse id="details" open={*open}>
+///                 <div class="card card-body">{"Details"}</div>
+///             </Collapse>
+///         </>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Collapse(props: &CollapseProps) -> Html {
+    let node_ref = use_node_ref();
+    let state = use_state(|| CollapseState {
+        phase: if props.open { Phase::Shown } else { Phase::Hidden },
+        height: 0,
+    });
+
+    // Start the transition when `open` changes
+    {
+        let state = state.clone();
+        let node_ref = node_ref.clone();
+        use_effect_with(props.open, move |open| {
+            match (open, state.phase) {
+                (true, Phase::Hidden | Phase::Hiding) => {
+                    state.set(CollapseState { phase: Phase::Showing, height: 0 });
+                }
+                (false, Phase::Shown | Phase::Showing) => {
+                    let height = node_ref.cast::<Element>().map(|node| node.scroll_height()).unwrap_or_default();
+                    state.set(CollapseState { phase: Phase::Hiding, height });
+                }
+                _ => (),
+            }
+        });
+    }
+
+    let finish = {
+        let state = state.clone();
+        let on_shown = props.on_shown.clone();
+        let on_hidden = props.on_hidden.clone();
+        Callback::from(move |phase: Phase| {
+            match phase {
+                Phase::Showing => {
+                    state.set(CollapseState { phase: Phase::Shown, height: 0 });
+                    on_shown.emit(());
+                }
+                Phase::Hiding => {
+                    state.set(CollapseState { phase: Phase::Hidden, height: 0 });
+                    on_hidden.emit(());
+                }
+                _ => (),
+            }
+        })
+    };
+
+    // Drive the transition: the start height is rendered first, then the target
+    // height is set so that the browser animates between both
+    {
+        let state = state.clone();
+        let node_ref = node_ref.clone();
+        let finish = finish.clone();
+        use_effect_with(*state, move |current| {
+            let mut timeout = None;
+            // Reading the height forces a reflow, so the start height is applied
+            let height = node_ref.cast::<Element>().map(|node| node.scroll_height()).unwrap_or_default();
+            match (current.phase, current.height) {
+                (Phase::Showing, 0) if height > 0 => {
+                    state.set(CollapseState { phase: Phase::Showing, height });
+                }
+                (Phase::Hiding, current_height) if current_height > 0 => {
+                    state.set(CollapseState { phase: Phase::Hiding, height: 0 });
+                }
+                (phase @ (Phase::Showing | Phase::Hiding), _) => {
+                    // No transitionend when there is nothing to animate or when the
+                    // transition is disabled (reduced motion), finish after its duration
+                    timeout = Some(Timeout::new(TRANSITION_DURATION + 50, move || finish.emit(phase)));
+                }
+                _ => (),
+            }
+            move || drop(timeout)
+        });
+    }
+
+    let ontransitionend = {
+        let phase = state.phase;
+        Callback::from(move |event: TransitionEvent| {
+            // Ignore transitions bubbling up from the content
+            if event.target() == event.current_target() {
+                finish.emit(phase);
+            }
+        })
+    };
+
+    let mut classes = match state.phase {
+        Phase::Hidden => classes!("collapse"),
+        Phase::Shown => classes!("collapse", "show"),
+        Phase::Showing | Phase::Hiding => classes!("collapsing"),
+    };
+    classes.push(props.class.clone());
+
+    let style = match state.phase {
+        Phase::Showing | Phase::Hiding => Some(format!("height: {}px", state.height)),
+        _ => None,
+    };
+
+    html! {
+        <div
+            ref={node_ref}
+            id={props.id.clone()}
+            class={classes}
+            style={style}
+            aria-labelledby={props.labelledby.clone()}
+            ontransitionend={ontransitionend}
+        >
+            { for props.children.iter() }
+        </div>
+    }
+}
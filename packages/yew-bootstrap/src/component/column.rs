@@ -1,6 +1,8 @@
 use log::warn;
 use yew::prelude::*;
 
+use crate::util::{AlignItems, JustifyContent};
+
 /// # Column container
 /// Used with [crate::component::Row] to create grids
 ///
@@ -19,6 +21,22 @@ use yew::prelude::*;
 ///     }
 /// }
 /// ```
+///
+/// A column can be made a flex container to center its content:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Column, Row};
+/// use yew_bootstrap::util::{AlignItems, JustifyContent};
+/// fn test() -> Html {
+///     html!{
+///         <Row>
+///             <Column lg=4 flex=true align_items={AlignItems::Center} justify_content={JustifyContent::Center}>
+///                 <p>{ "Centered" }</p>
+///             </Column>
+///         </Row>
+///     }
+/// }
+/// ```
 pub struct Column {}
 
 /// # Properties for [Column]
@@ -59,6 +77,18 @@ pub struct ColumnProps {
     /// Event called when the element is clicked
     #[prop_or_default]
     pub onclick: Callback<MouseEvent>,
+
+    /// If true, the column is a flex container (`d-flex`)
+    #[prop_or_default]
+    pub flex: bool,
+
+    /// Alignment of the content on the cross axis, only applies when `flex` is true
+    #[prop_or_default]
+    pub align_items: Option<AlignItems>,
+
+    /// Alignment of the content on the main axis, only applies when `flex` is true
+    #[prop_or_default]
+    pub justify_content: Option<JustifyContent>,
 }
 
 impl Component for Column {
@@ -112,6 +142,15 @@ impl Component for Column {
         if let Some(xxl) = props.xxl {
             classes.push("col-xxl-".to_string() + &xxl.to_string());
         }
+        if props.flex {
+            classes.push("d-flex");
+        }
+        if let Some(align_items) = &props.align_items {
+            classes.push(align_items.to_string());
+        }
+        if let Some(justify_content) = &props.justify_content {
+            classes.push(justify_content.to_string());
+        }
         classes.push(props.class.clone());
 
         html! {
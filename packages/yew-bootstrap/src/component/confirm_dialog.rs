@@ -0,0 +1,131 @@
+use yew::prelude::*;
+use web_sys::Element;
+
+use super::{Button, Modal, ModalBody, ModalFooter, ModalHeader};
+use crate::util::Color;
+
+/// # Properties of [ConfirmDialog]
+#[derive(Properties, Clone, PartialEq)]
+pub struct ConfirmDialogProps {
+    /// Html id of the dialog, used with the `modal_target` of a [Button] to open it
+    pub id: AttrValue,
+
+    /// Title displayed in the header
+    #[prop_or_default]
+    pub title: AttrValue,
+
+    /// Message displayed in the body
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Text of the confirm button, default "OK"
+    #[prop_or(AttrValue::from("OK"))]
+    pub confirm_text: AttrValue,
+
+    /// Text of the cancel button, default "Cancel"
+    #[prop_or(AttrValue::from("Cancel"))]
+    pub cancel_text: AttrValue,
+
+    /// Color of the confirm button, default [Color::Primary]
+    #[prop_or(Color::Primary)]
+    pub confirm_style: Color,
+
+    /// Color of the cancel button, default [Color::Secondary]
+    #[prop_or(Color::Secondary)]
+    pub cancel_style: Color,
+
+    /// Called with `true` when the confirm button is clicked, `false` when the dialog is
+    /// cancelled (cancel or close button, Escape key or click on the backdrop)
+    #[prop_or_default]
+    pub on_result: Callback<bool>,
+
+    /// If true, the dialog is not cancelled by the Escape key or a click on the backdrop,
+    /// only by its buttons. See the `static_backdrop` of [Modal].
+    #[prop_or_default]
+    pub static_backdrop: bool,
+}
+
+/// # Confirmation dialog
+/// A [Modal] asking the user to confirm or cancel an action.
+///
+/// The dialog is opened like any other [Modal], and closed by Bootstrap JS.
+/// The answer is sent to `on_result`.
+///
+/// See [ConfirmDialogProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Button, ConfirmDialog};
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     let on_result = Callback::from(|confirmed: bool| {
+///         if confirmed {
+///             // Delete the item
+///         }
+///     });
+///     html!{
+///         <>
+///             <ConfirmDialog
+///                 id="ConfirmDelete"
+///                 title="Delete item"
+///                 confirm_text="Delete"
+///                 confirm_style={Color::Danger}
+///                 on_result={on_result}
+///             >
+///                 {"This item will be permanently deleted."}
+///             </ConfirmDialog>
+///             <Button style={Color::Danger} modal_target="ConfirmDelete">{"Delete"}</Button>
+///         </>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn ConfirmDialog(props: &ConfirmDialogProps) -> Html {
+    let on_confirm = props.on_result.reform(|_: MouseEvent| true);
+    let on_cancel = props.on_result.reform(|_: MouseEvent| false);
+
+    // Clicks on the backdrop land on the `.modal` element itself
+    let onclick = {
+        let on_result = props.on_result.clone();
+        let static_backdrop = props.static_backdrop;
+        Callback::from(move |event: MouseEvent| {
+            if let Some(target) = event.target_dyn_into::<Element>() {
+                let classes = target.class_list();
+                if (classes.contains("modal") && !static_backdrop) || classes.contains("btn-close") {
+                    on_result.emit(false);
+                }
+            }
+        })
+    };
+
+    // Bootstrap ignores the Escape key with a static backdrop
+    let onkeydown = {
+        let on_result = props.on_result.clone();
+        let static_backdrop = props.static_backdrop;
+        Callback::from(move |event: KeyboardEvent| {
+            if event.key() == "Escape" && !static_backdrop {
+                on_result.emit(false);
+            }
+        })
+    };
+
+    html! {
+        <div onclick={onclick} onkeydown={onkeydown}>
+            <Modal id={props.id.to_string()} static_backdrop={props.static_backdrop}>
+                <ModalHeader title={props.title.to_string()} id={props.id.to_string()} />
+                <ModalBody>
+                    { for props.children.iter() }
+                </ModalBody>
+                <ModalFooter>
+                    <Button style={props.cancel_style.clone()} modal_dismiss={true} onclick={on_cancel}>
+                        { props.cancel_text.clone() }
+                    </Button>
+                    <Button style={props.confirm_style.clone()} modal_dismiss={true} onclick={on_confirm}>
+                        { props.confirm_text.clone() }
+                    </Button>
+                </ModalFooter>
+            </Modal>
+        </div>
+    }
+}
@@ -1,7 +1,16 @@
+use std::fmt;
+
 use log::*;
+use yew::html::IntoPropValue;
 use yew::prelude::*;
 
+use crate::util::{Breakpoint, Spacing};
+
 /// Size for a container, from extra small to extra large
+///
+/// Replaced by [Breakpoint], which converts from it: `ContainerSize::Medium.into()`.
+/// It can still be passed to [Container]'s `size`.
+#[deprecated(since = "0.8.0", note = "use util::Breakpoint")]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ContainerSize {
     ExtraSmall,
@@ -11,15 +20,57 @@ pub enum ContainerSize {
     ExtraLarge,
     ExtraExtraLarge,
 }
-impl ToString for ContainerSize {
-    fn to_string(&self) -> String {
+
+#[allow(deprecated)]
+impl fmt::Display for ContainerSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Breakpoint::from(self.clone()).name())
+    }
+}
+
+#[allow(deprecated)]
+impl From<ContainerSize> for Breakpoint {
+    fn from(size: ContainerSize) -> Self {
+        match size {
+            ContainerSize::ExtraSmall => Breakpoint::Xs,
+            ContainerSize::Small => Breakpoint::Sm,
+            ContainerSize::Medium => Breakpoint::Md,
+            ContainerSize::Large => Breakpoint::Lg,
+            ContainerSize::ExtraLarge => Breakpoint::Xl,
+            ContainerSize::ExtraExtraLarge => Breakpoint::Xxl,
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl IntoPropValue<Breakpoint> for ContainerSize {
+    fn into_prop_value(self) -> Breakpoint {
+        self.into()
+    }
+}
+
+/// Kind of container wrapping the content of a component, see [Container]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ContainerType {
+    /// `.container`, fixed width at each breakpoint
+    Fixed,
+    /// `.container-fluid`, full width at all breakpoints
+    #[default]
+    Fluid,
+    /// `.container-{breakpoint}`, full width until the given breakpoint
+    Responsive(Breakpoint),
+    /// No container, content is rendered directly
+    None,
+}
+
+impl ContainerType {
+    /// Wrap `content` in a [Container] of this type
+    pub fn wrap(&self, content: Html) -> Html {
         match self {
-            &ContainerSize::ExtraSmall => "".to_string(),
-            ContainerSize::Small => "sm".to_string(),
-            ContainerSize::Medium => "md".to_string(),
-            ContainerSize::Large => "lg".to_string(),
-            ContainerSize::ExtraLarge => "xl".to_string(),
-            ContainerSize::ExtraExtraLarge => "xxl".to_string(),
+            ContainerType::Fixed => html! { <Container>{content}</Container> },
+            ContainerType::Fluid => html! { <Container fluid=true>{content}</Container> },
+            ContainerType::Responsive(size) => html! { <Container size={*size}>{content}</Container> },
+            ContainerType::None => content,
         }
     }
 }
@@ -34,11 +85,27 @@ impl ToString for ContainerSize {
 /// 
 /// ```rust
 /// use yew::prelude::*;
-/// use yew_bootstrap::component::{Container, ContainerSize};
-/// use yew_bootstrap::util::Color;
+/// use yew_bootstrap::component::Container;
+/// use yew_bootstrap::util::Breakpoint;
 /// fn test() -> Html {
 ///     html!{
-///         <Container size={ContainerSize::Large} fluid={ true }/>
+///         <Container size={Breakpoint::Lg} fluid={ true }/>
+///     }
+/// }
+/// ```
+///
+/// The default horizontal padding can be removed for full-bleed sections, or increased:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Container;
+/// use yew_bootstrap::util::Spacing;
+/// fn test() -> Html {
+///     html!{
+///         <>
+///             <Container fluid={ true } padding_x={Spacing::S0}>{"Edge to edge hero"}</Container>
+///             <Container padding_x={Spacing::S5}>{"Roomier content"}</Container>
+///         </>
 ///     }
 /// }
 /// ```
@@ -55,13 +122,17 @@ pub struct ContainerProps {
     #[prop_or_default]
     pub children: Children,
 
-    /// Size of the container
-    #[prop_or(ContainerSize::ExtraSmall)]
-    pub size: ContainerSize,
+    /// Breakpoint until which the container is full width, default [Breakpoint::Xs]
+    #[prop_or_default]
+    pub size: Breakpoint,
 
     /// If true, fluid container - Size ignored and must be default.
     #[prop_or_default]
     pub fluid: bool,
+
+    /// Horizontal padding (`px-*`), replacing the default container padding
+    #[prop_or_default]
+    pub padding_x: Option<Spacing>,
 }
 
 impl Component for Container {
@@ -75,17 +146,20 @@ impl Component for Container {
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
         let mut classes = Classes::new();
-        // ExtraSmall have no size class
-        if props.size != ContainerSize::ExtraSmall {
+        // Xs has no size class
+        if props.size != Breakpoint::Xs {
             if props.fluid {
                 warn!("Fluid is set to true, but a size is also set. Fluid will be ignored.");
             }
-            classes.push(format!("container-{}", props.size.to_string()));
+            classes.push(format!("container{}", props.size.suffix()));
         } else if props.fluid {
             classes.push("container-fluid");
         } else {
             classes.push("container");
         }
+        if let Some(padding_x) = props.padding_x {
+            classes.push(format!("px-{}", padding_x));
+        }
         classes.push(props.class.clone());
 
         html! {
@@ -97,3 +171,22 @@ impl Component for Container {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use yew::LocalServerRenderer;
+
+    use super::*;
+
+    #[function_component]
+    #[allow(deprecated)]
+    fn OldSize() -> Html {
+        html! { <Container size={ContainerSize::Large} fluid={true} /> }
+    }
+
+    #[tokio::test]
+    async fn deprecated_size() {
+        let html = LocalServerRenderer::<OldSize>::new().hydratable(false).render().await;
+        assert_eq!(html, r#"<div class="container-lg"></div>"#);
+    }
+}
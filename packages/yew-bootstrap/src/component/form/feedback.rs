@@ -0,0 +1,53 @@
+use yew::prelude::*;
+
+use super::FormControlValidation;
+
+/// Help text and validation feedback of a form control, shared by the form components
+pub(crate) struct Feedback {
+    /// Help text, with id `{id}-help`
+    pub help: Option<Html>,
+    /// Validation feedback, with id `{id}-feedback`
+    pub validation: Option<Html>,
+    /// `is-valid` or `is-invalid` class of the control
+    pub validation_class: Option<&'static str>,
+    /// Ids of the help text and feedback, for the `aria-describedby` of the control
+    pub described_by: Option<AttrValue>,
+}
+
+impl Feedback {
+    /// Build the help text and validation feedback of the control `id`, with
+    /// `classes` for the valid and invalid feedback
+    pub fn new(
+        id: &str,
+        help: Option<&AttrValue>,
+        validation: &FormControlValidation,
+        (valid_class, invalid_class): (&'static str, &'static str),
+    ) -> Self {
+        let help_id = format!("{}-help", id);
+        let feedback_id = format!("{}-feedback", id);
+
+        let help = help.map(|text| html! {
+            <div class="form-text" id={ help_id.clone() }>{ text.clone() }</div>
+        });
+
+        let (validation, validation_class) = match validation {
+            FormControlValidation::None => (None, None),
+            FormControlValidation::Valid(None) => (None, Some("is-valid")),
+            FormControlValidation::Valid(Some(text)) => (Some(html! {
+                <div class={ valid_class } id={ feedback_id.clone() }>{ text.clone() }</div>
+            }), Some("is-valid")),
+            FormControlValidation::Invalid(text) => (Some(html! {
+                <div class={ invalid_class } id={ feedback_id.clone() }>{ text.clone() }</div>
+            }), Some("is-invalid")),
+        };
+
+        // The control is described by the help text and the feedback, when present
+        let described_by: Vec<String> = [
+            help.as_ref().map(|_| help_id),
+            validation.as_ref().map(|_| feedback_id),
+        ].into_iter().flatten().collect();
+        let described_by = (!described_by.is_empty()).then(|| AttrValue::from(described_by.join(" ")));
+
+        Self { help, validation, validation_class, described_by }
+    }
+}
@@ -1,5 +1,7 @@
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 use super::*;
+use super::feedback::Feedback;
 
 /// Validation type for a form control, with feedback message
 #[derive(Clone, PartialEq)]
@@ -83,6 +85,18 @@ pub struct FormControlProps {
     #[prop_or(FormControlValidation::None)]
     pub validation: FormControlValidation,
 
+    /// If true, the validation feedback is shown as a tooltip over the following
+    /// content instead of inline text, so it does not shift the layout. The field
+    /// is made `position-relative` to place the tooltip.
+    #[prop_or_default]
+    pub validation_tooltip: bool,
+
+    /// Only used for checkbox and radio: if true, the label is placed before the
+    /// control, on the opposite side. Requires Bootstrap 5.2 or later, it has no effect
+    /// with the 5.1 CSS of [crate::util::include_cdn] and [crate::util::include_inline].
+    #[prop_or_default]
+    pub reverse: bool,
+
     /// Optional onchange event applied on the input
     /// For a text input, this is called when leaving the input field
     #[prop_or_default]
@@ -96,6 +110,11 @@ pub struct FormControlProps {
     /// Optional onclick event applied on the input
     #[prop_or_default]
     pub onclick: Callback<MouseEvent>,
+
+    /// Only used for [FormControlType::Range]: if true, the current value is displayed
+    /// next to the slider, and updated while it is dragged
+    #[prop_or_default]
+    pub show_value: bool,
 }
 
 
@@ -205,6 +224,48 @@ where T: std::fmt::Display {
 /// }
 /// ```
 ///
+/// Checkboxes and radios can show the label before the control with `reverse`
+/// (Bootstrap 5.2 or later). A `form-switch` class turns a checkbox into a switch:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::form::*;
+/// fn test() -> Html {
+///   html! {
+///     <>
+///       <FormControl id="notify" ctype={ FormControlType::Checkbox } label="Notifications" reverse={ true } />
+///       <FormControl
+///           id="dark-mode"
+///           ctype={ FormControlType::Checkbox }
+///           class="form-switch"
+///           label="Dark mode"
+///           reverse={ true }
+///       />
+///     </>
+///   }
+/// }
+/// ```
+///
+/// In compact forms, the feedback can be shown as a tooltip so it does not move the
+/// fields below:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::form::*;
+/// fn test() -> Html {
+///   html! {
+///     <FormControl
+///         id="input-username"
+///         ctype={ FormControlType::Text }
+///         class="mb-3"
+///         label="Username"
+///         validation_tooltip={ true }
+///         validation={
+///             FormControlValidation::Invalid(AttrValue::from("This username is taken"))
+///         }
+///     />
+///   }
+/// }
+/// ```
+///
 /// `onclick`, `oninput` and `onchange` events are available. `onchange` should be preferred
 /// for most inputs, but for text inputs (`Text`, `TextArea`, `Number`, etc), `onchange` is
 /// only called when the input looses focus, while `oninput` is called each time a key is
@@ -265,6 +326,25 @@ where T: std::fmt::Display {
 
 #[function_component]
 pub fn FormControl(props: &FormControlProps) -> Html {
+    // Current value, displayed next to a range when `show_value` is set
+    let current_value = use_state(|| props.value.clone());
+    {
+        let current_value = current_value.clone();
+        use_effect_with(props.value.clone(), move |value| current_value.set(value.clone()));
+    }
+    let oninput = if props.show_value {
+        let current_value = current_value.clone();
+        let oninput = props.oninput.clone();
+        Callback::from(move |event: InputEvent| {
+            if let Some(input) = event.target_dyn_into::<HtmlInputElement>() {
+                current_value.set(AttrValue::from(input.value()));
+            }
+            oninput.emit(event);
+        })
+    } else {
+        props.oninput.clone()
+    };
+
     let label = match props.label.clone() {
         None => None,
         Some(text) => {
@@ -275,20 +355,14 @@ pub fn FormControl(props: &FormControlProps) -> Html {
         }
     };
 
-    let help = props.help.as_ref().map(|text| html! {
-        <div class="form-text">{ text.clone() }</div>
-    });
-
-    let (validation, validation_class) = match props.validation.clone() {
-        FormControlValidation::None => (None, None),
-        FormControlValidation::Valid(None) => (None, Some("is-valid")),
-        FormControlValidation::Valid(Some(text)) => (Some(html! {
-            <div class="valid-feedback"> { text.clone() }</div>
-        }), Some("is-valid")),
-        FormControlValidation::Invalid(text) => (Some(html! {
-            <div class="invalid-feedback"> { text.clone() }</div>
-        }), Some("is-invalid")),
+    let feedback_classes = if props.validation_tooltip {
+        ("valid-tooltip", "invalid-tooltip")
+    } else {
+        ("valid-feedback", "invalid-feedback")
     };
+    let Feedback { help, validation, validation_class, described_by } =
+        Feedback::new(&props.id, props.help.as_ref(), &props.validation, feedback_classes);
+    let tooltip_class = props.validation_tooltip.then_some("position-relative");
 
     let pattern = match &props.ctype {
         FormControlType::Email{ pattern } => pattern,
@@ -304,7 +378,7 @@ pub fn FormControl(props: &FormControlProps) -> Html {
 
     match &props.ctype {
         FormControlType::TextArea { cols, rows } => {
-            let mut classes = classes!(props.class.clone());
+            let mut classes = classes!(props.class.clone(), tooltip_class);
             if props.floating {
                 classes.push("form-floating");
             }
@@ -332,6 +406,7 @@ pub fn FormControl(props: &FormControlProps) -> Html {
                         onchange={ props.onchange.clone() }
                         onclick={ props.onclick.clone() }
                         required={ props.required }
+                        aria-describedby={ described_by.clone() }
                     />
                     { label_after }
                     { help }
@@ -340,7 +415,7 @@ pub fn FormControl(props: &FormControlProps) -> Html {
             }
         },
         FormControlType::Select => {
-            let mut classes = classes!(props.class.clone());
+            let mut classes = classes!(props.class.clone(), tooltip_class);
             if props.floating {
                 classes.push("form-floating");
             }
@@ -361,6 +436,7 @@ pub fn FormControl(props: &FormControlProps) -> Html {
                         onchange={ props.onchange.clone() }
                         onclick={ props.onclick.clone() }
                         required={ props.required }
+                        aria-describedby={ described_by.clone() }
                     >
                         { for props.children.clone() }
                     </select>
@@ -371,7 +447,10 @@ pub fn FormControl(props: &FormControlProps) -> Html {
             }
         },
         FormControlType::Checkbox | FormControlType::Radio => {
-            let mut classes = classes!("form-check");
+            let mut classes = classes!("form-check", tooltip_class);
+            if props.reverse {
+                classes.push("form-check-reverse");
+            }
             classes.push(props.class.clone());
 
             let input_classes = classes!("form-check-input", validation_class);
@@ -389,6 +468,7 @@ pub fn FormControl(props: &FormControlProps) -> Html {
                         onchange={ props.onchange.clone() }
                         onclick={ props.onclick.clone() }
                         required={ props.required }
+                        aria-describedby={ described_by.clone() }
                     />
                     { label }
                     { help }
@@ -418,15 +498,15 @@ pub fn FormControl(props: &FormControlProps) -> Html {
                     max_str = max.clone();
                 },
                 FormControlType::File { accept } => {
-                    let accept_vec : Vec<String> = accept.clone().iter().cloned().map(
-                        move |value| { value.to_string() }
+                    let accept_vec : Vec<String> = accept.iter().map(
+                        |value| { value.to_string() }
                     ).collect();
                     accept_str = Some(accept_vec.join(", "));
                 }
                 _ => ()
             }
 
-            let mut classes = classes!(props.class.clone());
+            let mut classes = classes!(props.class.clone(), tooltip_class);
             if props.floating {
                 classes.push("form-floating");
             }
@@ -436,27 +516,42 @@ pub fn FormControl(props: &FormControlProps) -> Html {
             let (label_before, label_after) =
                 if props.floating { (None, label) } else { (label, None) };
 
+            let input = html! {
+                <input
+                    type={ props.ctype.to_str() }
+                    class={ input_classes }
+                    id={ props.id.clone() }
+                    name={ props.name.clone() }
+                    value={ props.value.clone() }
+                    pattern={ pattern }
+                    accept={ accept_str }
+                    placeholder={ placeholder }
+                    min={ min_str }
+                    max={ max_str }
+                    step={ step_str }
+                    disabled={ props.disabled }
+                    onchange={ props.onchange.clone() }
+                    onclick={ props.onclick.clone() }
+                    oninput={ oninput }
+                    required={ props.required }
+                    aria-describedby={ described_by }
+                />
+            };
+
+            let input = match &props.ctype {
+                FormControlType::Range { .. } if props.show_value => html! {
+                    <div class="d-flex align-items-center">
+                        { input }
+                        <output for={ props.id.clone() } class="ms-2">{ (*current_value).clone() }</output>
+                    </div>
+                },
+                _ => input,
+            };
+
             html! {
                 <div class={ classes }>
                     { label_before }
-                    <input
-                        type={ props.ctype.to_str() }
-                        class={ input_classes }
-                        id={ props.id.clone() }
-                        name={ props.name.clone() }
-                        value={ props.value.clone() }
-                        pattern={ pattern }
-                        accept={ accept_str }
-                        placeholder={ placeholder }
-                        min={ min_str }
-                        max={ max_str }
-                        step={ step_str }
-                        disabled={ props.disabled }
-                        onchange={ props.onchange.clone() }
-                        onclick={ props.onclick.clone() }
-                        oninput={ props.oninput.clone() }
-                        required={ props.required }
-                    />
+                    { input }
                     { label_after }
                     { help }
                     { validation }
@@ -1,7 +1,16 @@
+mod feedback;
 mod form_type;
 mod form_control;
 mod select_option;
+mod radio_group;
+mod textarea;
+mod validation_summary;
+mod use_form;
 
 pub use form_type::*;
 pub use form_control::*;
-pub use select_option::*;
\ No newline at end of file
+pub use select_option::*;
+pub use radio_group::*;
+pub use textarea::*;
+pub use validation_summary::*;
+pub use use_form::*;
\ No newline at end of file
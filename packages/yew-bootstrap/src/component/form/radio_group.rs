@@ -0,0 +1,174 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use super::feedback::Feedback;
+use super::FormControlValidation;
+
+/// Option of a [FormRadioGroup]
+#[derive(Clone, PartialEq)]
+pub struct RadioOption {
+    /// Value submitted when this option is selected
+    pub value: AttrValue,
+    /// Label displayed next to the radio
+    pub label: AttrValue,
+    /// Option cannot be selected
+    pub disabled: bool,
+}
+
+impl RadioOption {
+    /// Create an enabled option
+    pub fn new(value: impl Into<AttrValue>, label: impl Into<AttrValue>) -> Self {
+        Self { value: value.into(), label: label.into(), disabled: false }
+    }
+
+    /// Disable this option
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+}
+
+/// # Properties of [FormRadioGroup]
+#[derive(Properties, Clone, PartialEq)]
+pub struct FormRadioGroupProps {
+    /// Name shared by all the radios of the group
+    pub name: AttrValue,
+
+    /// Options of the group
+    #[prop_or_default]
+    pub options: Vec<RadioOption>,
+
+    /// Value of the selected option, if any
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
+
+    /// Optional label of the group, rendered as a legend
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+
+    /// CSS class
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Display the radios on the same line
+    #[prop_or_default]
+    pub inline: bool,
+
+    /// Place the labels before the radios, on the opposite side. Requires Bootstrap 5.2
+    /// or later, it has no effect with the 5.1 CSS of [crate::util::include_cdn] and
+    /// [crate::util::include_inline].
+    #[prop_or_default]
+    pub reverse: bool,
+
+    /// Disable the whole group
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// Is a selection required? Defaults to false.
+    #[prop_or_default]
+    pub required: bool,
+
+    /// Validation feedback for the group as a whole
+    #[prop_or(FormControlValidation::None)]
+    pub validation: FormControlValidation,
+
+    /// Called with the value of the option selected by the user
+    #[prop_or_default]
+    pub on_change: Callback<String>,
+}
+
+/// # Radio group
+/// Set of radio buttons sharing a `name`, with one selected value. Radio ids are
+/// `{name}-{index}`.
+///
+/// See [FormRadioGroupProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::form::*;
+/// #[function_component]
+/// fn Shipping() -> Html {
+///     let selected = use_state(|| None::<AttrValue>);
+///     let on_change = {
+///         let selected = selected.clone();
+///         Callback::from(move |value: String| selected.set(Some(value.into())))
+///     };
+///     let validation = match *selected {
+///         None => FormControlValidation::Invalid("Select a shipping method".into()),
+///         Some(_) => FormControlValidation::None,
+///     };
+///     html!{
+///         <FormRadioGroup
+///             name="shipping"
+///             label="Shipping"
+///             options={vec![
+///                 RadioOption::new("standard", "Standard"),
+///                 RadioOption::new("express", "Express"),
+///                 RadioOption::new("drone", "Drone").disabled(),
+///             ]}
+///             value={(*selected).clone()}
+///             inline={true}
+///             validation={validation}
+///             on_change={on_change}
+///         />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn FormRadioGroup(props: &FormRadioGroupProps) -> Html {
+    // Feedback is not a sibling of the inputs, always display it
+    let Feedback { validation, validation_class, described_by, .. } = Feedback::new(
+        &props.name,
+        None,
+        &props.validation,
+        ("valid-feedback d-block", "invalid-feedback d-block"),
+    );
+
+    let onchange = {
+        let on_change = props.on_change.clone();
+        Callback::from(move |event: Event| {
+            if let Some(input) = event.target_dyn_into::<HtmlInputElement>() {
+                if input.checked() {
+                    on_change.emit(input.value());
+                }
+            }
+        })
+    };
+
+    let mut check_classes = classes!("form-check");
+    if props.inline {
+        check_classes.push("form-check-inline");
+    }
+    if props.reverse {
+        check_classes.push("form-check-reverse");
+    }
+
+    html! {
+        <fieldset class={ props.class.clone() } disabled={ props.disabled } aria-describedby={ described_by }>
+            if let Some(label) = &props.label {
+                <legend class="form-label fs-6">{ label.clone() }</legend>
+            }
+            { for props.options.iter().enumerate().map(|(index, option)| {
+                let id = format!("{}-{}", props.name, index);
+                html! {
+                    <div class={ check_classes.clone() }>
+                        <input
+                            type="radio"
+                            class={ classes!("form-check-input", validation_class) }
+                            id={ id.clone() }
+                            name={ props.name.clone() }
+                            value={ option.value.clone() }
+                            checked={ props.value.as_ref() == Some(&option.value) }
+                            disabled={ option.disabled }
+                            required={ props.required }
+                            onchange={ onchange.clone() }
+                        />
+                        <label class="form-check-label" for={ id }>{ option.label.clone() }</label>
+                    </div>
+                }
+            }) }
+            { validation }
+        </fieldset>
+    }
+}
@@ -0,0 +1,163 @@
+use web_sys::HtmlTextAreaElement;
+use yew::prelude::*;
+
+use super::feedback::Feedback;
+use super::FormControlValidation;
+
+/// # Properties of [FormTextarea]
+#[derive(Properties, Clone, PartialEq)]
+pub struct FormTextareaProps {
+    /// Id for the form field
+    pub id: AttrValue,
+
+    /// CSS class
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Optional label for the textarea
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+
+    /// Optional placeholder
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+
+    /// Optional help text
+    #[prop_or_default]
+    pub help: Option<AttrValue>,
+
+    /// Name for the form field
+    #[prop_or_default]
+    pub name: AttrValue,
+
+    /// Value of the textarea
+    #[prop_or_default]
+    pub value: AttrValue,
+
+    /// Number of visible lines. With `auto_resize`, this is the minimum height.
+    #[prop_or_default]
+    pub rows: Option<u32>,
+
+    /// Is this field required? Defaults to false.
+    #[prop_or_default]
+    pub required: bool,
+
+    /// Disabled if true
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// If true, the textarea grows to fit its content instead of scrolling
+    #[prop_or_default]
+    pub auto_resize: bool,
+
+    /// Form validation feedback
+    #[prop_or(FormControlValidation::None)]
+    pub validation: FormControlValidation,
+
+    /// Called with the new value each time the content is changed
+    #[prop_or_default]
+    pub on_change: Callback<String>,
+}
+
+/// Set the height of the textarea to the height of its content
+fn fit_content(textarea: &HtmlTextAreaElement) {
+    let style = textarea.style();
+    // Reset first, so the textarea can also shrink
+    let _ = style.set_property("height", "auto");
+    let _ = style.set_property("height", &format!("{}px", textarea.scroll_height()));
+}
+
+/// # Textarea field
+/// Multi-line text field, which can grow automatically to fit its content.
+///
+/// See [FormTextareaProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::form::FormTextarea;
+/// #[function_component]
+/// fn Comment() -> Html {
+///     let comment = use_state(String::new);
+///     let on_change = {
+///         let comment = comment.clone();
+///         Callback::from(move |value: String| comment.set(value))
+///     };
+///     html!{
+///         <FormTextarea
+///             id="comment"
+///             class="mb-3"
+///             label="Comment"
+///             rows={2}
+///             auto_resize={true}
+///             value={(*comment).clone()}
+///             on_change={on_change}
+///         />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn FormTextarea(props: &FormTextareaProps) -> Html {
+    let node_ref = use_node_ref();
+
+    // Fit the content when it is set by the parent, including the initial value
+    {
+        let node_ref = node_ref.clone();
+        use_effect_with((props.value.clone(), props.auto_resize), move |(_, auto_resize)| {
+            if *auto_resize {
+                if let Some(textarea) = node_ref.cast::<HtmlTextAreaElement>() {
+                    fit_content(&textarea);
+                }
+            }
+        });
+    }
+
+    let oninput = {
+        let on_change = props.on_change.clone();
+        let auto_resize = props.auto_resize;
+        Callback::from(move |event: InputEvent| {
+            if let Some(textarea) = event.target_dyn_into::<HtmlTextAreaElement>() {
+                if auto_resize {
+                    fit_content(&textarea);
+                }
+                on_change.emit(textarea.value());
+            }
+        })
+    };
+
+    let label = props.label.as_ref().map(|text| html! {
+        <label for={ props.id.clone() } class="form-label">{ text.clone() }</label>
+    });
+
+    let Feedback { help, validation, validation_class, described_by } = Feedback::new(
+        &props.id,
+        props.help.as_ref(),
+        &props.validation,
+        ("valid-feedback", "invalid-feedback"),
+    );
+
+    // Hide the scrollbar while resizing to the content
+    let style = props.auto_resize.then_some("overflow-y: hidden; resize: none");
+
+    html! {
+        <div class={ props.class.clone() }>
+            { label }
+            <textarea
+                ref={ node_ref }
+                class={ classes!("form-control", validation_class) }
+                id={ props.id.clone() }
+                name={ props.name.clone() }
+                rows={ props.rows.map(|rows| rows.to_string()) }
+                placeholder={ props.placeholder.clone() }
+                value={ props.value.clone() }
+                disabled={ props.disabled }
+                required={ props.required }
+                style={ style }
+                aria-describedby={ described_by }
+                oninput={ oninput }
+            />
+            { help }
+            { validation }
+        </div>
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use wasm_bindgen::JsCast;
+use web_sys::{EventTarget, HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+use super::FormControlValidation;
+
+/// Values of a form managed by [use_form], indexed by field id.
+///
+/// Radio buttons are indexed by their `name`, and hold the value of the checked radio.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FormValues {
+    values: HashMap<AttrValue, AttrValue>,
+    checked: HashMap<AttrValue, bool>,
+    touched: HashSet<AttrValue>,
+    submitted: bool,
+}
+
+impl FormValues {
+    /// Create an empty set of values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the initial value of a text or select field
+    pub fn with_value(mut self, id: impl Into<AttrValue>, value: impl Into<AttrValue>) -> Self {
+        self.values.insert(id.into(), value.into());
+        self
+    }
+
+    /// Set the initial state of a checkbox
+    pub fn with_checked(mut self, id: impl Into<AttrValue>, checked: bool) -> Self {
+        self.checked.insert(id.into(), checked);
+        self
+    }
+
+    /// Value of a text or select field, empty if not set
+    pub fn value(&self, id: &str) -> AttrValue {
+        self.values.get(id).cloned().unwrap_or_default()
+    }
+
+    /// State of a checkbox, false if not set
+    pub fn checked(&self, id: &str) -> bool {
+        self.checked.get(id).copied().unwrap_or_default()
+    }
+
+    /// True if the field has been changed by the user
+    pub fn touched(&self, id: &str) -> bool {
+        self.touched.contains(id)
+    }
+
+    /// True once the user tried to submit the form
+    pub fn submitted(&self) -> bool {
+        self.submitted
+    }
+}
+
+/// Change applied to [FormValues] by [UseFormHandle]
+pub enum FormAction {
+    /// Set the value of a text or select field, or of a radio group
+    SetValue(AttrValue, AttrValue),
+    /// Set the state of a checkbox
+    SetChecked(AttrValue, bool),
+    /// Mark the form as submitted, so the errors of all fields are shown
+    Submit,
+}
+
+impl Reducible for FormValues {
+    type Action = FormAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut values = (*self).clone();
+        match action {
+            FormAction::SetValue(id, value) => {
+                values.touched.insert(id.clone());
+                values.values.insert(id, value);
+            }
+            FormAction::SetChecked(id, checked) => {
+                values.touched.insert(id.clone());
+                values.checked.insert(id, checked);
+            }
+            FormAction::Submit => values.submitted = true,
+        }
+        Rc::new(values)
+    }
+}
+
+/// Read the value of the form control that triggered an event
+fn read_target(target: Option<EventTarget>) -> Option<FormAction> {
+    let target = target?;
+    if let Some(input) = target.dyn_ref::<HtmlInputElement>() {
+        return match &input.type_()[..] {
+            "checkbox" => Some(FormAction::SetChecked(input.id().into(), input.checked())),
+            "radio" if input.checked() => Some(FormAction::SetValue(input.name().into(), input.value().into())),
+            "radio" => None,
+            _ => Some(FormAction::SetValue(input.id().into(), input.value().into())),
+        };
+    }
+    if let Some(select) = target.dyn_ref::<HtmlSelectElement>() {
+        return Some(FormAction::SetValue(select.id().into(), select.value().into()));
+    }
+    if let Some(textarea) = target.dyn_ref::<HtmlTextAreaElement>() {
+        return Some(FormAction::SetValue(textarea.id().into(), textarea.value().into()));
+    }
+    None
+}
+
+/// Handle returned by [use_form]
+#[derive(Clone)]
+pub struct UseFormHandle {
+    values: UseReducerHandle<FormValues>,
+    errors: Rc<Vec<(AttrValue, AttrValue)>>,
+}
+
+impl UseFormHandle {
+    /// Current values of the form
+    pub fn values(&self) -> &FormValues {
+        &self.values
+    }
+
+    /// Value of a text or select field, to pass as `value`
+    pub fn value(&self, id: &str) -> AttrValue {
+        self.values.value(id)
+    }
+
+    /// State of a checkbox, to pass as `checked`
+    pub fn checked(&self, id: &str) -> bool {
+        self.values.checked(id)
+    }
+
+    /// Set the value of a text or select field
+    pub fn set_value(&self, id: impl Into<AttrValue>, value: impl Into<AttrValue>) {
+        self.values.dispatch(FormAction::SetValue(id.into(), value.into()));
+    }
+
+    /// Callback updating the form from any control, to pass as `onchange`
+    pub fn onchange(&self) -> Callback<Event> {
+        let values = self.values.clone();
+        Callback::from(move |event: Event| {
+            if let Some(action) = read_target(event.target()) {
+                values.dispatch(action);
+            }
+        })
+    }
+
+    /// Callback updating the form after each key press, to pass as `oninput` on text fields
+    pub fn oninput(&self) -> Callback<InputEvent> {
+        let values = self.values.clone();
+        Callback::from(move |event: InputEvent| {
+            if let Some(action) = read_target(event.target()) {
+                values.dispatch(action);
+            }
+        })
+    }
+
+    /// Mark the form as submitted, so the errors of the fields not changed by the user
+    /// are shown too. Call it when the user tries to submit the form.
+    pub fn submit(&self) {
+        self.values.dispatch(FormAction::Submit);
+    }
+
+    /// Validation of a field, to pass as `validation`. Fields are only shown as valid or
+    /// invalid once they have been changed by the user, or the form has been submitted.
+    pub fn validation(&self, id: &str) -> FormControlValidation {
+        if !self.values.touched(id) && !self.values.submitted() {
+            return FormControlValidation::None;
+        }
+        match self.errors.iter().find(|(field, _)| field == id) {
+            Some((_, message)) => FormControlValidation::Invalid(message.clone()),
+            None => FormControlValidation::Valid(None),
+        }
+    }
+
+    /// All errors, as (field id, message), for example to pass to
+    /// [crate::component::form::ValidationSummary]
+    pub fn errors(&self) -> Vec<(AttrValue, AttrValue)> {
+        self.errors.to_vec()
+    }
+
+    /// True if the validation function did not return any error
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// # Form state hook
+/// Keeps the values of the controls of a form, and validates them.
+///
+/// - `initial` returns the initial [FormValues]
+/// - `validate` returns the errors for the current values, as (field id, message)
+///
+/// Fields are identified by their `id`, except radio buttons which are grouped by `name`.
+/// The [UseFormHandle] provides the `value`, `checked`, `onchange`/`oninput` and
+/// `validation` to bind to each [crate::component::form::FormControl]. Errors are shown
+/// once a field is changed, or for all fields after [UseFormHandle::submit].
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Button, ButtonType};
+/// use yew_bootstrap::component::form::*;
+/// #[function_component]
+/// fn Signup() -> Html {
+///     let form = use_form(
+///         || FormValues::new().with_value("email", "").with_checked("terms", false),
+///         |values| {
+///             let mut errors = vec![];
+///             if values.value("email").is_empty() {
+///                 errors.push((AttrValue::from("email"), AttrValue::from("Email is required")));
+///             }
+///             if !values.checked("terms") {
+///                 errors.push((AttrValue::from("terms"), AttrValue::from("Please accept the terms")));
+///             }
+///             errors
+///         },
+///     );
+///     let onsubmit = {
+///         let form = form.clone();
+///         Callback::from(move |event: SubmitEvent| {
+///             event.prevent_default();
+///             form.submit();
+///             if form.is_valid() {
+///                 log::info!("Signed up as {}", form.value("email"));
+///             }
+///         })
+///     };
+///     html! {
+///         <form onsubmit={onsubmit}>
+///             <FormControl
+///                 id="email"
+///                 ctype={FormControlType::Email { pattern: None }}
+///                 label="Email"
+///                 value={form.value("email")}
+///                 oninput={form.oninput()}
+///                 validation={form.validation("email")}
+///             />
+///             <FormControl
+///                 id="terms"
+///                 ctype={FormControlType::Checkbox}
+///                 label="I accept the terms"
+///                 checked={form.checked("terms")}
+///                 onchange={form.onchange()}
+///                 validation={form.validation("terms")}
+///             />
+///             <Button button_type={ButtonType::Submit} text="Sign up" />
+///         </form>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_form<I, V>(initial: I, validate: V) -> UseFormHandle
+where
+    I: FnOnce() -> FormValues,
+    V: Fn(&FormValues) -> Vec<(AttrValue, AttrValue)>,
+{
+    let values = use_reducer(initial);
+    let errors = Rc::new(validate(&values));
+    UseFormHandle { values, errors }
+}
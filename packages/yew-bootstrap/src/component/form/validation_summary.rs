@@ -0,0 +1,83 @@
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use crate::component::Alert;
+use crate::util::Color;
+
+/// # Properties of [ValidationSummary]
+#[derive(Properties, Clone, PartialEq)]
+pub struct ValidationSummaryProps {
+    /// List of errors, as (field id, message). The field id is the id of the
+    /// invalid form control.
+    #[prop_or_default]
+    pub errors: Vec<(AttrValue, AttrValue)>,
+
+    /// Title shown above the list of errors
+    #[prop_or(AttrValue::from("Please correct the following errors:"))]
+    pub title: AttrValue,
+
+    /// CSS class
+    #[prop_or_default]
+    pub class: String,
+}
+
+/// # Validation summary
+/// Summary of the errors of a form, usually placed at the top of the form. Each error
+/// links to the invalid field and focuses it when clicked.
+///
+/// Nothing is rendered when there are no errors.
+///
+/// See [ValidationSummaryProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::form::{FormControl, FormControlType, ValidationSummary};
+/// fn test() -> Html {
+///     let errors = vec![
+///         (AttrValue::from("email"), AttrValue::from("Email is required")),
+///     ];
+///     html!{
+///         <form>
+///             <ValidationSummary errors={errors} />
+///             <FormControl id="email" ctype={FormControlType::Email { pattern: None }} label="Email" />
+///         </form>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn ValidationSummary(props: &ValidationSummaryProps) -> Html {
+    if props.errors.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <Alert style={Color::Danger} class={props.class.clone()}>
+            <p class="mb-1">{ props.title.clone() }</p>
+            <ul class="mb-0">
+                { for props.errors.iter().map(|(id, message)| {
+                    let onclick = {
+                        let id = id.clone();
+                        Callback::from(move |event: MouseEvent| {
+                            let field = gloo::utils::document()
+                                .get_element_by_id(&id)
+                                .and_then(|field| field.dyn_into::<HtmlElement>().ok());
+                            if let Some(field) = field {
+                                event.prevent_default();
+                                let _ = field.focus();
+                            }
+                        })
+                    };
+                    html! {
+                        <li>
+                            <a class="alert-link" href={format!("#{}", id)} onclick={onclick}>
+                                { message.clone() }
+                            </a>
+                        </li>
+                    }
+                }) }
+            </ul>
+        </Alert>
+    }
+}
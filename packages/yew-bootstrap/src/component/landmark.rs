@@ -0,0 +1,113 @@
+use log::warn;
+use yew::prelude::*;
+
+use super::ContainerType;
+
+/// # Properties of [Main], [Section], [Article] and [Aside]
+#[derive(Properties, Clone, PartialEq)]
+pub struct LandmarkProps {
+    /// CSS class
+    #[prop_or_default]
+    pub class: String,
+
+    /// Html id of the element
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+
+    /// Accessible name of the landmark. A [Section] is only exposed as a
+    /// landmark to assistive technologies when it has a name.
+    #[prop_or_default]
+    pub aria_label: Option<AttrValue>,
+
+    /// Container wrapping the content, default [ContainerType::None]
+    #[prop_or(ContainerType::None)]
+    pub container: ContainerType,
+
+    /// Vertical padding (`py-*`), from 0 to 5
+    #[prop_or_default]
+    pub padding_y: Option<u8>,
+
+    /// Vertical margin (`my-*`), from 0 to 5
+    #[prop_or_default]
+    pub margin_y: Option<u8>,
+
+    /// Inner components
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Render a landmark element with the given tag
+fn landmark(tag: &'static str, props: &LandmarkProps) -> Html {
+    let mut classes = Classes::new();
+    if let Some(padding_y) = props.padding_y {
+        if padding_y > 5 {
+            warn!("Landmark `padding_y` cannot be greater than 5");
+        }
+        classes.push(format!("py-{}", padding_y));
+    }
+    if let Some(margin_y) = props.margin_y {
+        if margin_y > 5 {
+            warn!("Landmark `margin_y` cannot be greater than 5");
+        }
+        classes.push(format!("my-{}", margin_y));
+    }
+    classes.push(props.class.clone());
+
+    html! {
+        <@{tag} class={classes} id={props.id.clone()} aria-label={props.aria_label.clone()}>
+            { props.container.wrap(html! { <>{ for props.children.iter() }</> }) }
+        </@>
+    }
+}
+
+/// # Main landmark
+/// Renders a `<main>` element, holding the main content of the page.
+///
+/// See [LandmarkProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Article, Aside, ContainerType, Main, Section};
+/// fn test() -> Html {
+///     html!{
+///         <Main container={ContainerType::Fixed} padding_y=4>
+///             <Section aria_label="News" margin_y=3>
+///                 <Article><h2>{"Headline"}</h2></Article>
+///             </Section>
+///             <Aside aria_label="Related links">{"Links"}</Aside>
+///         </Main>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Main(props: &LandmarkProps) -> Html {
+    landmark("main", props)
+}
+
+/// # Section landmark
+/// Renders a `<section>` element, a thematic group of content.
+///
+/// See [LandmarkProps] for a listing of properties, and [Main] for an example.
+#[function_component]
+pub fn Section(props: &LandmarkProps) -> Html {
+    landmark("section", props)
+}
+
+/// # Article landmark
+/// Renders an `<article>` element, a self-contained piece of content.
+///
+/// See [LandmarkProps] for a listing of properties, and [Main] for an example.
+#[function_component]
+pub fn Article(props: &LandmarkProps) -> Html {
+    landmark("article", props)
+}
+
+/// # Aside landmark
+/// Renders an `<aside>` element, content indirectly related to the main content.
+///
+/// See [LandmarkProps] for a listing of properties, and [Main] for an example.
+#[function_component]
+pub fn Aside(props: &LandmarkProps) -> Html {
+    landmark("aside", props)
+}
@@ -1,3 +1,6 @@
+use std::fmt;
+
+use log::warn;
 use yew::prelude::*;
 use crate::util::Color;
 
@@ -20,8 +23,51 @@ use crate::util::Color;
 ///     }
 /// }
 /// ```
+///
+/// The underline can be styled using the Bootstrap 5.3 link utilities:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Link, UnderlineOpacity};
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html!{
+///         <Link
+///             style={Color::Success}
+///             underline_color={Color::Success}
+///             underline_opacity={UnderlineOpacity::Percent25}
+///             underline_offset={2}
+///             text={ "Link text" }
+///         />
+///     }
+/// }
+/// ```
 pub struct Link {}
 
+/// Opacity of the underline of a [Link] (Bootstrap 5.3)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnderlineOpacity {
+    Percent0,
+    Percent10,
+    Percent25,
+    Percent50,
+    Percent75,
+    Percent100,
+}
+
+impl fmt::Display for UnderlineOpacity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UnderlineOpacity::Percent0 => write!(f, "0"),
+            UnderlineOpacity::Percent10 => write!(f, "10"),
+            UnderlineOpacity::Percent25 => write!(f, "25"),
+            UnderlineOpacity::Percent50 => write!(f, "50"),
+            UnderlineOpacity::Percent75 => write!(f, "75"),
+            UnderlineOpacity::Percent100 => write!(f, "100"),
+        }
+    }
+}
+
 /// Properties for [Link]
 #[derive(Properties, Clone, PartialEq)]
 pub struct LinkProps {
@@ -44,6 +90,18 @@ pub struct LinkProps {
     /// Optional text for the link
     #[prop_or_default]
     pub text: String,
+
+    /// Color of the underline, independent of the link color (Bootstrap 5.3)
+    #[prop_or_default]
+    pub underline_color: Option<Color>,
+
+    /// Opacity of the underline (Bootstrap 5.3)
+    #[prop_or_default]
+    pub underline_opacity: Option<UnderlineOpacity>,
+
+    /// Offset of the underline from the text, from 1 to 3 (Bootstrap 5.3)
+    #[prop_or_default]
+    pub underline_offset: Option<u8>,
 }
 
 impl Component for Link {
@@ -63,6 +121,18 @@ impl Component for Link {
         if props.stretched {
             classes.push("stretched-link");
         }
+        if let Some(color) = &props.underline_color {
+            classes.push(format!("link-underline-{}", color));
+        }
+        if let Some(opacity) = props.underline_opacity {
+            classes.push(format!("link-underline-opacity-{}", opacity));
+        }
+        if let Some(offset) = props.underline_offset {
+            if !(1..=3).contains(&offset) {
+                warn!("Link `underline_offset` must be between 1 and 3");
+            }
+            classes.push(format!("link-offset-{}", offset));
+        }
         classes.push(props.class.clone());
 
         html! {
@@ -1,27 +1,24 @@
+use std::rc::Rc;
+
+use web_sys::{Element, ScrollIntoViewOptions, ScrollLogicalPosition};
 use yew::prelude::*;
-use crate::util::Color;
-use super::*;
+use crate::util::{Breakpoint, Color};
 
 /// The variant style of a [ListGroup]
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub enum ListGroupVariant {
     /// Default style, with rounded corners and outer borders.
+    #[default]
     Default,
     /// Flush style, removes rounding and border.
     Flush,
 }
 
-impl Default for ListGroupVariant {
-    fn default() -> Self {
-        ListGroupVariant::Default
-    }
-}
-
 /// A size threshold to trigger a property at
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum SizeTrigger {
     /// Trigger at the given size boundary
-    AtSize(ContainerSize),
+    AtSize(Breakpoint),
     /// Always active
     Always,
     /// Never active
@@ -46,6 +43,46 @@ pub struct ListGroupProps {
     /// Control when the list is displayed horizontally. Always, or at a certain container size.
     #[prop_or(SizeTrigger::Never)]
     pub horizontal: SizeTrigger,
+    /// Whether items can be reordered by the user, with drag and drop or with
+    /// Alt + arrow keys. The list is not changed, `on_reorder` must be handled.
+    #[prop_or_default]
+    pub reorderable: bool,
+    /// Called with the (from, to) positions of an item moved by the user, only
+    /// used when `reorderable` is true
+    #[prop_or_default]
+    pub on_reorder: Callback<(usize, usize)>,
+    /// Whether the active item is scrolled into view when it becomes active, for
+    /// long lists in a scrollable container
+    #[prop_or_default]
+    pub scroll_to_active: bool,
+}
+
+/// Drag and drop event of a reorderable [ListGroup], with the position of the item
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ReorderEvent {
+    /// Item starts being dragged
+    Start(usize),
+    /// Dragged item is over this item
+    Over(usize),
+    /// Dragged item is dropped on this item
+    Drop(usize),
+    /// Dragging ended, dropped or not
+    End,
+}
+
+/// Reordering state passed by a reorderable [ListGroup] to its items
+#[derive(Clone, PartialEq)]
+pub(crate) struct Reorder {
+    /// Position of the item in the list
+    index: usize,
+    /// Item is being dragged
+    dragging: bool,
+    /// Dragged item is over this item
+    over: bool,
+    /// Called on drag and drop events
+    on_drag: Callback<ReorderEvent>,
+    /// Called with the new position when the item is moved with the keyboard
+    on_move: Callback<usize>,
 }
 
 /// # ListGroup component
@@ -71,9 +108,59 @@ pub struct ListGroupProps {
 ///         </ListGroup>
 ///     }
 /// }
+/// ```
 ///
+/// In a scrollable container, the active item can be kept visible when it is changed
+/// by the application:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{ListGroup, ListGroupItem};
+/// fn test(files: &[&str], selected: usize) -> Html {
+///     html! {
+///         <div style="max-height: 300px; overflow-y: auto">
+///             <ListGroup scroll_to_active=true>
+///                 { for files.iter().enumerate().map(|(index, file)| html_nested! {
+///                     <ListGroupItem active={index == selected}>{*file}</ListGroupItem>
+///                 }) }
+///             </ListGroup>
+///         </div>
+///     }
+/// }
+/// ```
+///
+/// Items can be reordered by the user, the list is updated in `on_reorder`:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{ListGroup, ListGroupItem};
+/// #[function_component]
+/// fn Priorities() -> Html {
+///     let items = use_state(|| vec!["First", "Second", "Third"]);
+///     let on_reorder = {
+///         let items = items.clone();
+///         Callback::from(move |(from, to): (usize, usize)| {
+///             let mut list = (*items).clone();
+///             let item = list.remove(from);
+///             list.insert(to, item);
+///             items.set(list);
+///         })
+///     };
+///     html! {
+///         <ListGroup reorderable=true on_reorder={on_reorder}>
+///             { for items.iter().map(|item| html_nested! {
+///                 <ListGroupItem key={*item}>{*item}</ListGroupItem>
+///             }) }
+///         </ListGroup>
+///     }
+/// }
+/// ```
 #[function_component]
 pub fn ListGroup(props: &ListGroupProps) -> Html {
+    // Positions of the dragged item and of the item it is over
+    let dragging = use_state(|| None::<usize>);
+    let over = use_state(|| None::<usize>);
+
     let mut classes = Classes::from("list-group");
 
     classes.extend(&props.class);
@@ -86,16 +173,69 @@ pub fn ListGroup(props: &ListGroupProps) -> Html {
     match &props.horizontal {
         SizeTrigger::Never => (),
         SizeTrigger::Always => classes.push("list-group-horizontal"),
-        SizeTrigger::AtSize(size) => classes.push(format!("list-group-horizontal-{}", size.to_string())),
+        SizeTrigger::AtSize(breakpoint) => classes.push(format!("list-group-horizontal{}", breakpoint.suffix())),
     }
 
     if props.numbered {
         classes.push("list-group-numbered")
     }
 
+    if !props.reorderable {
+        return html! {
+            <div class={classes}>
+                { for props.children.iter().map(|mut child| {
+                    if props.scroll_to_active {
+                        Rc::make_mut(&mut child.props).scroll_on_active = true;
+                    }
+                    child
+                }) }
+            </div>
+        }
+    }
+
+    let on_drag = {
+        let dragging = dragging.clone();
+        let over = over.clone();
+        let on_reorder = props.on_reorder.clone();
+        Callback::from(move |event: ReorderEvent| match event {
+            ReorderEvent::Start(index) => dragging.set(Some(index)),
+            ReorderEvent::Over(index) => over.set(Some(index)),
+            ReorderEvent::Drop(index) => {
+                if let Some(from) = *dragging {
+                    if from != index {
+                        on_reorder.emit((from, index));
+                    }
+                }
+                dragging.set(None);
+                over.set(None);
+            }
+            ReorderEvent::End => {
+                dragging.set(None);
+                over.set(None);
+            }
+        })
+    };
+    let count = props.children.len();
+
     html! {
         <div class={classes}>
-            { for props.children.iter() }
+            { for props.children.iter().enumerate().map(|(index, mut child)| {
+                let on_reorder = props.on_reorder.clone();
+                let child_props = Rc::make_mut(&mut child.props);
+                child_props.scroll_on_active = props.scroll_to_active;
+                child_props.reorder = Some(Reorder {
+                    index,
+                    dragging: *dragging == Some(index),
+                    over: *over == Some(index),
+                    on_drag: on_drag.clone(),
+                    on_move: Callback::from(move |to: usize| {
+                        if to < count {
+                            on_reorder.emit((index, to));
+                        }
+                    }),
+                });
+                child
+            }) }
         </div>
     }
 }
@@ -127,6 +267,27 @@ pub struct ListGroupItemProps {
     /// Event called when the list item is clicked
     #[prop_or_default]
     pub onclick: Callback<MouseEvent>,
+    /// Optional title. When set, the item uses a two-line layout: the title (truncated
+    /// if too long) and `meta` on the first line, then the children and `subtitle`.
+    #[prop_or_default]
+    pub title: Option<AttrValue>,
+    /// Secondary text displayed in small print below the children, only used with `title`
+    #[prop_or_default]
+    pub subtitle: Option<AttrValue>,
+    /// Content displayed at the end of the title line, typically a date or a [Badge],
+    /// only used with `title`
+    #[prop_or_default]
+    pub meta: Option<Html>,
+    /// Shows a small trailing spinner and ignores clicks while true, a link item does
+    /// not navigate to its `url`. Combine with `disabled` to also grey out the item.
+    #[prop_or_default]
+    pub loading: bool,
+    /// Set by a reorderable [ListGroup]
+    #[prop_or_default]
+    reorder: Option<Reorder>,
+    /// Set by a [ListGroup] with `scroll_to_active`
+    #[prop_or_default]
+    scroll_on_active: bool,
 }
 
 /// # ListGroupItem
@@ -150,9 +311,45 @@ pub struct ListGroupItemProps {
 ///         </ListGroup>
 ///     }
 /// }
+/// ```
 ///
+/// Items with a title use a two-line layout, with optional meta content and subtitle:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Badge, ListGroup, ListGroupItem};
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html! {
+///         <ListGroup>
+///             <ListGroupItem
+///                 action=true
+///                 title="Meeting notes"
+///                 subtitle="From Alice"
+///                 meta={html!{ <Badge style={Color::Primary} pill=true>{"3"}</Badge> }}
+///             >
+///                 {"Notes from the weekly meeting."}
+///             </ListGroupItem>
+///         </ListGroup>
+///     }
+/// }
+/// ```
 #[function_component]
 pub fn ListGroupItem(props: &ListGroupItemProps) -> Html {
+    let node_ref = use_node_ref();
+    {
+        let node_ref = node_ref.clone();
+        use_effect_with((props.active, props.scroll_on_active), move |(active, scroll_on_active)| {
+            if *active && *scroll_on_active {
+                if let Some(element) = node_ref.cast::<Element>() {
+                    let options = ScrollIntoViewOptions::new();
+                    options.set_block(ScrollLogicalPosition::Nearest);
+                    element.scroll_into_view_with_scroll_into_view_options(&options);
+                }
+            }
+        });
+    }
+
     let mut classes = Classes::from("list-group-item");
 
     classes.extend(&props.class);
@@ -167,24 +364,116 @@ pub fn ListGroupItem(props: &ListGroupItemProps) -> Html {
         classes.push(format!("list-group-item-{}", style));
     }
 
+    // Only phrasing content, the item can be rendered as a <button>
+    let content = match &props.title {
+        None => props.children.clone().into(),
+        Some(title) => html! {
+            <>
+                <span class="d-flex w-100 justify-content-between">
+                    <span class="h5 mb-1 me-2 text-truncate">{ title.clone() }</span>
+                    { props.meta.clone() }
+                </span>
+                <span class="d-block mb-1">{ props.children.clone() }</span>
+                if let Some(subtitle) = &props.subtitle {
+                    <small>{ subtitle.clone() }</small>
+                }
+            </>
+        },
+    };
+
+    let onclick = if props.loading { Callback::noop() } else { props.onclick.clone() };
+
+    let mut ondragstart = None;
+    let mut ondragover = None;
+    let mut ondrop = None;
+    let mut ondragend = None;
+    let mut onkeydown = None;
+    if let Some(reorder) = &props.reorder {
+        if reorder.dragging {
+            classes.push("opacity-50");
+        }
+        if reorder.over && !reorder.dragging {
+            classes.push("border-primary");
+        }
+        let Reorder { index, on_drag, on_move, over, .. } = reorder.clone();
+        ondragstart = Some(on_drag.reform(move |event: DragEvent| {
+            if let Some(transfer) = event.data_transfer() {
+                transfer.set_effect_allowed("move");
+                // Firefox does not start dragging without data
+                let _ = transfer.set_data("text/plain", &index.to_string());
+            }
+            ReorderEvent::Start(index)
+        }));
+        ondragover = Some({
+            let on_drag = on_drag.clone();
+            Callback::from(move |event: DragEvent| {
+                // Allow dropping on this item
+                event.prevent_default();
+                // dragover fires continuously, only report when the hovered item changes
+                if !over {
+                    on_drag.emit(ReorderEvent::Over(index));
+                }
+            })
+        });
+        ondrop = Some(on_drag.reform(move |event: DragEvent| {
+            event.prevent_default();
+            ReorderEvent::Drop(index)
+        }));
+        ondragend = Some(on_drag.reform(|_: DragEvent| ReorderEvent::End));
+        onkeydown = Some(Callback::from(move |event: KeyboardEvent| {
+            if !event.alt_key() {
+                return;
+            }
+            match &event.key()[..] {
+                "ArrowUp" | "ArrowLeft" if index > 0 => on_move.emit(index - 1),
+                "ArrowDown" | "ArrowRight" => on_move.emit(index + 1),
+                _ => return,
+            }
+            event.prevent_default();
+        }));
+    }
+    let draggable = props.reorder.as_ref().map(|_| "true");
+    // Plain items must be focusable to be moved with the keyboard
+    let tabindex = props.reorder.as_ref().map(|_| "0");
+    let content = if props.loading {
+        classes.push("d-flex align-items-center");
+        html! {
+            <>
+                <span class="flex-grow-1 overflow-hidden">{ content }</span>
+                // Same as a small Spinner, but a span: the item can be a <button>
+                <span class="spinner-border spinner-border-sm text-secondary ms-2 flex-shrink-0" role="status">
+                    <span class="visually-hidden">{ "Loading..." }</span>
+                </span>
+            </>
+        }
+    } else {
+        content
+    };
+
     if props.action && props.url.is_some() {
         classes.push("list-group-item-action");
+        // Without href, the link cannot be followed while loading
+        let href = if props.loading { None } else { props.url.clone() };
         html! {
-            <a class={classes} href={&props.url} onclick={props.onclick.clone()}>
-                {props.children.clone()}
+            <a ref={node_ref} class={classes} href={href} onclick={onclick} aria-busy={props.loading.then_some("true")}
+                aria-disabled={props.loading.then_some("true")}
+                draggable={draggable} {ondragstart} {ondragover} {ondrop} {ondragend} {onkeydown}>
+                {content}
             </a>
         }
     } else if props.action {
         classes.push("list-group-item-action");
         html! {
-            <button class={classes} onclick={props.onclick.clone()}>
-                {props.children.clone()}
+            <button ref={node_ref} type="button" class={classes} onclick={onclick} aria-busy={props.loading.then_some("true")}
+                draggable={draggable} {ondragstart} {ondragover} {ondrop} {ondragend} {onkeydown}>
+                {content}
             </button>
         }
     } else {
         html! {
-            <div class={classes}>
-                {props.children.clone()}
+            <div ref={node_ref} class={classes} aria-busy={props.loading.then_some("true")} tabindex={tabindex}
+                draggable={draggable} {ondragstart} {ondragover} {ondrop} {ondragend} {onkeydown}>
+                {content}
             </div>
         }
     }
@@ -0,0 +1,98 @@
+use yew::prelude::*;
+
+use super::{Placeholder, PlaceholderAnimation, Spinner};
+
+/// What a [Loading] component shows until its content is loaded
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LoadingIndicator {
+    /// A [Spinner]
+    #[default]
+    Spinner,
+    /// A skeleton of [Placeholder] lines
+    Placeholder,
+}
+
+/// # Properties of [Loading]
+#[derive(Properties, Clone, PartialEq)]
+pub struct LoadingProps {
+    /// CSS class
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// If true, the children are shown instead of the loading indicator
+    #[prop_or_default]
+    pub loaded: bool,
+
+    /// Loading indicator, default [LoadingIndicator::Spinner]
+    #[prop_or_default]
+    pub indicator: LoadingIndicator,
+
+    /// Number of placeholder lines of the skeleton. Default 3.
+    #[prop_or(3)]
+    pub lines: usize,
+
+    /// Animation of the placeholder skeleton, default [PlaceholderAnimation::Glow]
+    #[prop_or(PlaceholderAnimation::Glow)]
+    pub animation: PlaceholderAnimation,
+
+    /// Text for assistive technologies while loading
+    #[prop_or(AttrValue::from("Loading..."))]
+    pub label: AttrValue,
+
+    /// Content, shown once loaded
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// # Loading component
+/// Shows a [Spinner] or a [Placeholder] skeleton until `loaded` is set, then
+/// shows its children.
+///
+/// See [LoadingProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Loading, LoadingIndicator};
+/// #[function_component]
+/// fn Profile() -> Html {
+///     let name = use_state(|| None::<String>);
+///     html!{
+///         <Loading loaded={name.is_some()} indicator={LoadingIndicator::Placeholder} lines={2}>
+///             <p>{ (*name).clone().unwrap_or_default() }</p>
+///         </Loading>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Loading(props: &LoadingProps) -> Html {
+    if props.loaded {
+        return html! {
+            <div class={props.class.clone()}>
+                { for props.children.iter() }
+            </div>
+        };
+    }
+
+    match props.indicator {
+        LoadingIndicator::Spinner => html! {
+            <div class={props.class.clone()} aria-busy="true">
+                <Spinner label={props.label.clone()} />
+            </div>
+        },
+        LoadingIndicator::Placeholder => html! {
+            <div class={props.class.clone()} aria-busy="true">
+                <span class="visually-hidden">{ props.label.clone() }</span>
+                {
+                    // Vary the width of the lines, the last one is shorter
+                    for (0..props.lines).map(|line| {
+                        let width = if line + 1 == props.lines { 6 } else { [12, 10, 11][line % 3] };
+                        html! {
+                            <Placeholder width={width} animation={props.animation} />
+                        }
+                    })
+                }
+            </div>
+        },
+    }
+}
@@ -1,34 +1,58 @@
 mod alert;
+mod alert_stack;
+mod breadcrumb;
 mod button;
 mod button_group;
+mod collapse;
 mod column;
+mod confirm_dialog;
 mod container;
 pub mod form;
+mod landmark;
 mod line;
 mod link;
 mod list_group;
+mod loading;
 mod modal;
 mod navbar;
+mod pagination;
+mod placeholder;
+mod progress;
+mod rating;
 mod row;
 mod badge;
 mod spinner;
+mod stepper;
+mod toast;
 mod display;
 mod lead;
 mod accordion;
 
+pub use self::collapse::*;
 pub use self::column::*;
+pub use self::confirm_dialog::*;
 pub use self::alert::*;
+pub use self::alert_stack::*;
+pub use self::breadcrumb::*;
 pub use self::button::*;
 pub use self::button_group::*;
 pub use self::container::*;
+pub use self::landmark::*;
 pub use self::line::*;
 pub use self::link::*;
 pub use self::list_group::*;
+pub use self::loading::*;
 pub use self::modal::*;
 pub use self::navbar::*;
+pub use self::pagination::*;
+pub use self::placeholder::*;
+pub use self::progress::*;
+pub use self::rating::*;
 pub use self::row::*;
 pub use self::badge::*;
 pub use self::spinner::*;
+pub use self::stepper::*;
+pub use self::toast::*;
 pub use self::display::*;
 pub use self::lead::*;
 pub use self::accordion::*;
\ No newline at end of file
@@ -1,18 +1,30 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, PointerEvent};
 use yew::prelude::*;
 
+use crate::util::{Breakpoint, Size};
+
 /// Represents the optional size of a Modal dialog, described [here](https://getbootstrap.com/docs/5.1/components/modal/#optional-sizes)
-#[derive(Clone, PartialEq, Eq)]
+/// and [here](https://getbootstrap.com/docs/5.1/components/modal/#fullscreen-modal)
+#[derive(Clone, Default, PartialEq, Eq)]
 pub enum ModalSize {
     ExtraLarge,
     Large,
+    /// Standard width, no size class
+    #[default]
+    Default,
+    /// Former name of [ModalSize::Default]
+    #[deprecated(since = "0.8.0", note = "use ModalSize::Default")]
     Normal,
     Small,
-}
-
-impl Default for ModalSize {
-    fn default() -> Self {
-        ModalSize::Normal
-    }
+    /// Always covers the whole viewport
+    Fullscreen,
+    /// Covers the whole viewport below the given breakpoint
+    FullscreenBelow(Breakpoint),
 }
 
 /// # Modal dialog
@@ -27,7 +39,7 @@ impl Default for ModalSize {
 /// use yew_bootstrap::util::Color;
 /// fn test() -> Html {
 ///     html!{
-///         <Modal id="ExampleModal" size={ModalSize::Large}> // size defaults to Normal
+///         <Modal id="ExampleModal" size={ModalSize::Large}> // size defaults to Default
 ///             <ModalHeader title="Modal title" id="ExampleModal"/>
 ///             <ModalBody>
 ///                 <p>{"Modal body text goes here."}</p>
@@ -40,7 +52,127 @@ impl Default for ModalSize {
 ///     }
 /// }
 /// ```
-pub struct Modal { }
+///
+/// A modal can also cover the whole viewport, either always or only below a breakpoint:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Modal, ModalBody, ModalHeader, ModalSize};
+/// use yew_bootstrap::util::Breakpoint;
+/// fn test() -> Html {
+///     html!{
+///         <Modal id="FullscreenModal" size={ModalSize::FullscreenBelow(Breakpoint::Md)}>
+///             <ModalHeader title="Fullscreen below md" id="FullscreenModal"/>
+///             <ModalBody>
+///                 <p>{"Modal body text goes here."}</p>
+///             </ModalBody>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// Callbacks are called at each phase of the Bootstrap modal lifecycle, for example to
+/// focus a field once the modal is visible:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Modal, ModalBody, ModalHeader};
+/// fn test() -> Html {
+///     html!{
+///         <Modal
+///             id="CallbackModal"
+///             on_shown={Callback::from(|_| log::info!("Modal visible"))}
+///             on_hidden={Callback::from(|_| log::info!("Modal hidden"))}
+///         >
+///             <ModalHeader title="Lifecycle" id="CallbackModal"/>
+///             <ModalBody>
+///                 <p>{"Modal body text goes here."}</p>
+///             </ModalBody>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// The dialog can be vertically centered, and its body can scroll when the content is
+/// longer than the viewport:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Modal, ModalBody, ModalHeader};
+/// fn test() -> Html {
+///     html!{
+///         <Modal id="TermsModal" centered={true} scrollable={true}>
+///             <ModalHeader title="Terms of service" id="TermsModal"/>
+///             <ModalBody>
+///                 <p>{"Very long terms of service."}</p>
+///             </ModalBody>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// With `static_backdrop`, clicking outside the dialog or pressing Escape does not close
+/// it, so the user cannot lose their work by accident:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Button, Modal, ModalBody, ModalFooter, ModalHeader};
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html!{
+///         <Modal id="EditModal" static_backdrop={true}>
+///             <ModalHeader title="Edit profile" id="EditModal"/>
+///             <ModalBody>
+///                 <p>{"Form goes here."}</p>
+///             </ModalBody>
+///             <ModalFooter>
+///                 <Button style={Color::Secondary} modal_dismiss={true}>{"Discard"}</Button>
+///                 <Button style={Color::Primary}>{"Save"}</Button>
+///             </ModalFooter>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// With `draggable`, the dialog can be moved around the viewport by its [ModalHeader].
+/// It is back in its default position each time it is opened:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Modal, ModalBody, ModalHeader};
+/// fn test() -> Html {
+///     html!{
+///         <Modal id="ToolModal" draggable={true}>
+///             <ModalHeader title="Tools" id="ToolModal"/>
+///             <ModalBody>
+///                 <p>{"Drag the header to move this dialog."}</p>
+///             </ModalBody>
+///         </Modal>
+///     }
+/// }
+/// ```
+pub struct Modal {
+    node_ref: NodeRef,
+    listeners: Vec<EventListener>,
+    /// Modal element, header and `draggable` the listeners are bound for
+    bound: Option<(web_sys::Node, Option<Element>, bool)>,
+    /// Lifecycle callbacks (show, shown, hide, hidden), updated on each render so the
+    /// listeners do not need to be bound again
+    callbacks: Rc<RefCell<[Callback<()>; 4]>>,
+    /// Offset of the dialog moved with `draggable`
+    offset: Rc<Cell<(f64, f64)>>,
+    /// Drag in progress, kept across renders
+    drag: Rc<RefCell<Option<Drag>>>,
+}
+
+/// Pointer position and dialog offset when a drag started, and the allowed offset
+/// range keeping the dialog in the viewport
+struct Drag {
+    start: (f64, f64),
+    origin: (f64, f64),
+    x_range: (f64, f64),
+    y_range: (f64, f64),
+}
 
 /// # Header for a [Modal] dialog
 /// See [ModalHeaderProps] for a listing of properties
@@ -48,6 +180,23 @@ pub struct ModalHeader { }
 
 /// # Body for a [Modal] dialog
 /// See [ModalBodyProps] for a listing of properties
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Modal, ModalHeader, ModalBody};
+/// use yew_bootstrap::util::Size;
+/// fn test() -> Html {
+///     html!{
+///         <Modal id="LongModal">
+///             <ModalHeader title="Long content" id="LongModal"/>
+///             <ModalBody max_height={Size::Px(300)}>
+///                 <p>{"Long modal body text goes here."}</p>
+///             </ModalBody>
+///         </Modal>
+///     }
+/// }
+/// ```
 pub struct ModalBody { }
 
 /// # Footer for a [Modal] dialog
@@ -116,7 +265,10 @@ impl Component for ModalHeader {
 #[derive(Properties, Clone, PartialEq)]
 pub struct ModalBodyProps {
     #[prop_or_default]
-    pub children: Children
+    pub children: Children,
+    /// If set, the body scrolls past this height while the header and footer stay in place
+    #[prop_or_default]
+    pub max_height: Option<Size>,
 }
 
 impl Component for ModalBody {
@@ -130,8 +282,10 @@ impl Component for ModalBody {
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
 
+        let css = props.max_height.as_ref().map(|height| format!("max-height: {}; overflow-y: auto", height));
+
         html! {
-            <div class="modal-body">
+            <div class="modal-body" style={css}>
                 { for props.children.iter() }
             </div>
         }
@@ -152,6 +306,130 @@ pub struct ModalProps {
     /// Size of the modal
     #[prop_or_default]
     pub size: ModalSize,
+    /// Called when the modal starts opening (`show.bs.modal`)
+    #[prop_or_default]
+    pub on_show: Callback<()>,
+    /// Called when the modal is visible, after the fade transition (`shown.bs.modal`)
+    #[prop_or_default]
+    pub on_shown: Callback<()>,
+    /// Called when the modal starts closing (`hide.bs.modal`)
+    #[prop_or_default]
+    pub on_hide: Callback<()>,
+    /// Called when the modal is hidden, after the fade transition (`hidden.bs.modal`)
+    #[prop_or_default]
+    pub on_hidden: Callback<()>,
+    /// Element the modal is rendered into, default the document body. The modal is
+    /// rendered in a portal so that a parent `transform` or `overflow` cannot clip it.
+    #[prop_or_default]
+    pub container: Option<Element>,
+    /// If true, the dialog can be dragged by its header. The dialog is kept inside the
+    /// viewport, and is back in its default position when opened again.
+    #[prop_or_default]
+    pub draggable: bool,
+    /// If true, the dialog is vertically centered in the viewport
+    #[prop_or_default]
+    pub centered: bool,
+    /// If true, the dialog fits in the viewport and its [ModalBody] scrolls, while the
+    /// header and footer stay in place
+    #[prop_or_default]
+    pub scrollable: bool,
+    /// If true, the modal is not closed by clicking the backdrop or pressing Escape,
+    /// only by its buttons
+    #[prop_or_default]
+    pub static_backdrop: bool,
+}
+
+impl Modal {
+    /// Move the dialog with pointer events on the modal header
+    fn bind_drag(&mut self, element: &web_sys::Node) {
+        let Some(element) = element.dyn_ref::<Element>() else {
+            return;
+        };
+        let dialog = element.query_selector(".modal-dialog").ok().flatten()
+            .and_then(|dialog| dialog.dyn_into::<HtmlElement>().ok());
+        let header = element.query_selector(".modal-header").ok().flatten()
+            .and_then(|header| header.dyn_into::<HtmlElement>().ok());
+        let (Some(dialog), Some(header)) = (dialog, header) else {
+            return;
+        };
+        let _ = header.style().set_property("cursor", "move");
+        let _ = header.style().set_property("touch-action", "none");
+
+        let drag = self.drag.clone();
+
+        // Reset the position each time the modal is opened
+        let reset = {
+            let offset = self.offset.clone();
+            let dialog = dialog.clone();
+            EventListener::new(element, "show.bs.modal", move |_| {
+                offset.set((0.0, 0.0));
+                let _ = dialog.style().remove_property("transform");
+            })
+        };
+
+        let start = {
+            let drag = drag.clone();
+            let offset = self.offset.clone();
+            let dialog = dialog.clone();
+            let capture = header.clone();
+            EventListener::new(&header, "pointerdown", move |event| {
+                let Some(event) = event.dyn_ref::<PointerEvent>() else {
+                    return;
+                };
+                // Let the close button and other controls in the header work normally
+                let on_control = event.target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                    .and_then(|target| target.closest("button, a, input").ok().flatten())
+                    .is_some();
+                if event.button() != 0 || on_control {
+                    return;
+                }
+                let Some(content) = dialog.first_element_child() else {
+                    return;
+                };
+                let rect = content.get_bounding_client_rect();
+                let window = gloo::utils::window();
+                let width = window.inner_width().ok().and_then(|width| width.as_f64()).unwrap_or_default();
+                let height = window.inner_height().ok().and_then(|height| height.as_f64()).unwrap_or_default();
+                let origin = offset.get();
+                *drag.borrow_mut() = Some(Drag {
+                    start: (event.client_x() as f64, event.client_y() as f64),
+                    origin,
+                    x_range: (origin.0 - rect.left(), origin.0 + width - rect.right()),
+                    y_range: (origin.1 - rect.top(), origin.1 + height - rect.bottom()),
+                });
+                let _ = capture.set_pointer_capture(event.pointer_id());
+                event.prevent_default();
+            })
+        };
+
+        let moved = {
+            let drag = drag.clone();
+            let offset = self.offset.clone();
+            EventListener::new(&header, "pointermove", move |event| {
+                let (Some(event), Some(drag)) = (event.dyn_ref::<PointerEvent>(), &*drag.borrow()) else {
+                    return;
+                };
+                // Not using clamp(), the range is empty if the dialog is larger than the viewport
+                let x = (drag.origin.0 + event.client_x() as f64 - drag.start.0)
+                    .min(drag.x_range.1).max(drag.x_range.0);
+                let y = (drag.origin.1 + event.client_y() as f64 - drag.start.1)
+                    .min(drag.y_range.1).max(drag.y_range.0);
+                offset.set((x, y));
+                let _ = dialog.style().set_property("transform", &format!("translate({}px, {}px)", x, y));
+            })
+        };
+
+        let end = ["pointerup", "pointercancel"].map(|event| {
+            let drag = drag.clone();
+            EventListener::new(&header, event, move |_| {
+                drag.borrow_mut().take();
+            })
+        });
+
+        self.listeners.extend([reset, start, moved]);
+        self.listeners.extend(end);
+    }
 }
 
 impl Component for Modal {
@@ -159,30 +437,185 @@ impl Component for Modal {
     type Properties = ModalProps;
 
     fn create(_ctx: &Context<Self>) -> Self {
-        Self {}
+        Self {
+            node_ref: NodeRef::default(),
+            listeners: Vec::new(),
+            bound: None,
+            callbacks: Rc::default(),
+            offset: Rc::new(Cell::new((0.0, 0.0))),
+            drag: Rc::default(),
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        let props = ctx.props();
+        *self.callbacks.borrow_mut() = [
+            props.on_show.clone(),
+            props.on_shown.clone(),
+            props.on_hide.clone(),
+            props.on_hidden.clone(),
+        ];
+        let Some(element) = self.node_ref.get() else {
+            return;
+        };
+        // Only bind again when the elements change, so a render during a drag does not
+        // interrupt it
+        let header = element.dyn_ref::<Element>()
+            .and_then(|element| element.query_selector(".modal-header").ok().flatten());
+        let bound = Some((element.clone(), header, props.draggable));
+        if self.bound == bound {
+            return;
+        }
+        self.bound = bound;
+
+        // Bootstrap JS dispatches these events on the modal element
+        self.listeners = ["show.bs.modal", "shown.bs.modal", "hide.bs.modal", "hidden.bs.modal"]
+            .into_iter()
+            .enumerate()
+            .map(|(index, event)| {
+                let callbacks = self.callbacks.clone();
+                EventListener::new(&element, event, move |_| {
+                    let callback = callbacks.borrow()[index].clone();
+                    callback.emit(());
+                })
+            })
+            .collect();
+
+        if props.draggable {
+            self.drag.borrow_mut().take();
+            self.bind_drag(&element);
+        }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
+        let modal = modal_html(props, &self.node_ref);
+        match props.container.clone() {
+            Some(host) => create_portal(modal, host),
+            #[cfg(target_arch = "wasm32")]
+            None => create_portal(modal, gloo::utils::body().into()),
+            // No document outside the browser (server side rendering), render in place
+            #[cfg(not(target_arch = "wasm32"))]
+            None => modal,
+        }
+    }
+}
 
-        let mut dialog_classes = Classes::new();
-        dialog_classes.push("modal-dialog");
+/// Markup of the modal, rendered by [Modal] into its container
+fn modal_html(props: &ModalProps, node_ref: &NodeRef) -> Html {
+    let mut dialog_classes = Classes::new();
+    dialog_classes.push("modal-dialog");
 
-        match props.size {
-            ModalSize::ExtraLarge => dialog_classes.push("modal-xl"),
-            ModalSize::Large => dialog_classes.push("modal-lg"),
-            ModalSize::Small => dialog_classes.push("modal-sm"),
-            _ => (),
+    match &props.size {
+        ModalSize::ExtraLarge => dialog_classes.push("modal-xl"),
+        ModalSize::Large => dialog_classes.push("modal-lg"),
+        ModalSize::Small => dialog_classes.push("modal-sm"),
+        ModalSize::Fullscreen | ModalSize::FullscreenBelow(Breakpoint::Xs) => {
+            dialog_classes.push("modal-fullscreen")
+        }
+        ModalSize::FullscreenBelow(breakpoint) => {
+            dialog_classes.push(format!("modal-fullscreen{}down", breakpoint))
         }
+        #[allow(deprecated)]
+        ModalSize::Default | ModalSize::Normal => (),
+    }
+    if props.centered {
+        dialog_classes.push("modal-dialog-centered");
+    }
+    if props.scrollable {
+        dialog_classes.push("modal-dialog-scrollable");
+    }
 
-        html! {
-            <div class="modal" tabindex="-1" id={props.id.clone()}>
-                <div class={dialog_classes}>
-                    <div class="modal-content">
-                        { for props.children.iter() }
-                    </div>
+    html! {
+        <div
+            class="modal"
+            tabindex="-1"
+            id={props.id.clone()}
+            ref={node_ref.clone()}
+            data-bs-backdrop={props.static_backdrop.then_some("static")}
+            data-bs-keyboard={props.static_backdrop.then_some("false")}
+        >
+            <div class={dialog_classes}>
+                <div class="modal-content">
+                    { for props.children.iter() }
                 </div>
             </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use yew::LocalServerRenderer;
+
+    use super::*;
+
+    #[derive(Properties, PartialEq)]
+    struct TestProps {
+        size: ModalSize,
+    }
+
+    #[function_component]
+    fn TestModal(props: &TestProps) -> Html {
+        let props = yew::props!(ModalProps { id: "test", size: props.size.clone() });
+        modal_html(&props, &NodeRef::default())
+    }
+
+    async fn render(size: ModalSize) -> String {
+        LocalServerRenderer::<TestModal>::with_props(TestProps { size })
+            .hydratable(false)
+            .render()
+            .await
+    }
+
+    #[tokio::test]
+    async fn size_class_on_dialog() {
+        let cases = [
+            (ModalSize::Default, "modal-dialog"),
+            (ModalSize::Small, "modal-dialog modal-sm"),
+            (ModalSize::Large, "modal-dialog modal-lg"),
+            (ModalSize::ExtraLarge, "modal-dialog modal-xl"),
+            (ModalSize::Fullscreen, "modal-dialog modal-fullscreen"),
+            (ModalSize::FullscreenBelow(Breakpoint::Xs), "modal-dialog modal-fullscreen"),
+            (ModalSize::FullscreenBelow(Breakpoint::Sm), "modal-dialog modal-fullscreen-sm-down"),
+            (ModalSize::FullscreenBelow(Breakpoint::Md), "modal-dialog modal-fullscreen-md-down"),
+            (ModalSize::FullscreenBelow(Breakpoint::Lg), "modal-dialog modal-fullscreen-lg-down"),
+            (ModalSize::FullscreenBelow(Breakpoint::Xl), "modal-dialog modal-fullscreen-xl-down"),
+            (ModalSize::FullscreenBelow(Breakpoint::Xxl), "modal-dialog modal-fullscreen-xxl-down"),
+        ];
+        for (size, class) in cases {
+            let html = render(size).await;
+            assert!(html.contains(&format!(r#"<div class="{}"><div class="modal-content">"#, class)), "{}", html);
+        }
+    }
+
+    #[tokio::test]
+    async fn size_class_not_on_modal() {
+        let html = render(ModalSize::Large).await;
+        assert!(html.contains(r#"class="modal">"#), "{}", html);
+        assert_eq!(html.matches("modal-lg").count(), 1, "{}", html);
+        assert!(html.contains(r#"<div class="modal-dialog modal-lg">"#), "{}", html);
+    }
+
+    #[function_component]
+    fn ServerModal() -> Html {
+        html! {
+            <Modal id="test" size={ModalSize::Small}>
+                <ModalBody>{ "Body" }</ModalBody>
+            </Modal>
         }
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn server_side_without_container() {
+        let html = LocalServerRenderer::<ServerModal>::new().hydratable(false).render().await;
+        assert!(html.contains(r#"<div class="modal-dialog modal-sm"><div class="modal-content">"#), "{}", html);
+        assert!(html.contains("Body"), "{}", html);
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn normal_is_default() {
+        assert_eq!(render(ModalSize::Normal).await, render(ModalSize::Default).await);
+    }
+}
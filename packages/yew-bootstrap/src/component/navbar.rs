@@ -1,6 +1,8 @@
 use yew::prelude::*;
-use super::Container;
-use crate::util::Dimension;
+use yew::virtual_dom::VChild;
+use super::{Button, ButtonType, ContainerType};
+use crate::util::{use_scroll_direction, Color, Dimension, ScrollDirection};
+use web_sys::{HtmlElement, HtmlInputElement};
 use crate::icons::BI;
 
 /// # A singular dropdown item, child of [NavDropdown]
@@ -185,6 +187,112 @@ impl Component for NavItem {
     }
 }
 
+/// # Properties of [NavBarSearch]
+#[derive(Properties, Clone, PartialEq)]
+pub struct NavBarSearchProps {
+    /// URL the search form is submitted to. If not set, submitting calls `on_submit`
+    /// instead of leaving the page.
+    #[prop_or_default]
+    pub action: Option<AttrValue>,
+
+    /// Name of the search field
+    #[prop_or(AttrValue::from("q"))]
+    pub name: AttrValue,
+
+    /// Initial value of the search field
+    #[prop_or_default]
+    pub value: AttrValue,
+
+    /// Placeholder of the search field
+    #[prop_or(AttrValue::from("Search"))]
+    pub placeholder: AttrValue,
+
+    /// Accessible label of the search field
+    #[prop_or(AttrValue::from("Search"))]
+    pub aria_label: AttrValue,
+
+    /// Text of the submit button
+    #[prop_or(AttrValue::from("Search"))]
+    pub button_text: AttrValue,
+
+    /// Color of the outlined submit button, default [Color::Success]
+    #[prop_or(Color::Success)]
+    pub style: Color,
+
+    /// Called with the text of the search field each time it is changed
+    #[prop_or_default]
+    pub on_input: Callback<String>,
+
+    /// Called with the text of the search field when the form is submitted, if `action` is not set
+    #[prop_or_default]
+    pub on_submit: Callback<String>,
+}
+
+/// # Search form of a [NavBar]
+/// Search field and submit button laid out for the navbar, in a `role="search"` form.
+/// Pass it to the `search` property of the [NavBar] to place it after the items.
+///
+/// See [NavBarSearchProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{NavBar, NavBarSearch, NavItem};
+///
+/// fn test() -> Html {
+///     html!{
+///         <NavBar
+///             nav_id={"search-nav"}
+///             class="navbar-expand-lg navbar-light bg-light"
+///             search={html_nested!{
+///                 <NavBarSearch on_submit={Callback::from(|query: String| log::info!("Search {}", query))} />
+///             }}
+///         >
+///             <NavItem text="Home" url={AttrValue::from("/")} />
+///         </NavBar>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn NavBarSearch(props: &NavBarSearchProps) -> Html {
+    let value = use_state(|| props.value.to_string());
+
+    let oninput = {
+        let value = value.clone();
+        let on_input = props.on_input.clone();
+        Callback::from(move |event: InputEvent| {
+            if let Some(input) = event.target_dyn_into::<HtmlInputElement>() {
+                value.set(input.value());
+                on_input.emit(input.value());
+            }
+        })
+    };
+
+    let onsubmit = props.action.is_none().then(|| {
+        let value = value.clone();
+        let on_submit = props.on_submit.clone();
+        Callback::from(move |event: SubmitEvent| {
+            event.prevent_default();
+            on_submit.emit((*value).clone());
+        })
+    });
+
+    html! {
+        <form class="d-flex" role="search" action={props.action.clone()} onsubmit={onsubmit}>
+            <input
+                class="form-control me-2"
+                type="search"
+                name={props.name.clone()}
+                value={(*value).clone()}
+                placeholder={props.placeholder.clone()}
+                aria-label={props.aria_label.clone()}
+                oninput={oninput}
+            />
+            <Button style={props.style.clone()} outline={true} button_type={ButtonType::Submit} text={props.button_text.to_string()} />
+        </form>
+    }
+}
+
 /// # Brand type for a [NavBar]
 /// 
 /// This can contain a text, icon, image or combined (text and image)
@@ -243,6 +351,50 @@ pub enum BrandType {
 ///     }
 /// }
 /// ```
+///
+/// On small screens, the items can be shown in an offcanvas instead of a collapse:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{NavBar, NavItem};
+///
+/// fn test() -> Html {
+///     html!{
+///         <NavBar nav_id={"offcanvas-nav"} class="navbar-expand-lg navbar-light bg-light" offcanvas={true} offcanvas_title="Menu">
+///             <NavItem text="Home" url={AttrValue::from("/")} />
+///         </NavBar>
+///     }
+/// }
+/// ```
+///
+/// The navbar content is wrapped in a fluid container by default. It can be aligned
+/// with the page content using a fixed or responsive container:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{ContainerType, NavBar, NavItem};
+/// use yew_bootstrap::util::Breakpoint;
+///
+/// fn test() -> Html {
+///     html!{
+///         <NavBar nav_id={"fixed-nav"} class="navbar-expand-lg navbar-light bg-light" container={ContainerType::Responsive(Breakpoint::Lg)}>
+///             <NavItem text="Home" url={AttrValue::from("/")} />
+///         </NavBar>
+///     }
+/// }
+/// ```
+///
+/// A fixed navbar can be hidden while the page is scrolled down:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{NavBar, NavItem};
+///
+/// fn test() -> Html {
+///     html!{
+///         <NavBar nav_id={"scroll-nav"} class="navbar-expand-lg navbar-light bg-light fixed-top" hide_on_scroll={true}>
+///             <NavItem text="Home" url={AttrValue::from("/")} />
+///         </NavBar>
+///     }
+/// }
+/// ```
 pub struct NavBar { }
 
 /// Properties for [NavBar]
@@ -268,7 +420,34 @@ pub struct NavBarProps {
 
     /// Callback when brand is clicked
     #[prop_or_default]
-    pub brand_callback: Callback<MouseEvent>
+    pub brand_callback: Callback<MouseEvent>,
+
+    /// If true, the collapsible content is rendered inside an offcanvas, opened by the toggler,
+    /// below the breakpoint set by the `navbar-expand-*` class. Above the breakpoint, the items
+    /// are shown inline. Requires Bootstrap 5.2 or later: with the 5.1 CSS of
+    /// [crate::util::include_cdn] and [crate::util::include_inline], the inline items keep the
+    /// width and position of the offcanvas.
+    #[prop_or_default]
+    pub offcanvas: bool,
+
+    /// Title shown in the offcanvas header, only used when `offcanvas` is true
+    #[prop_or_default]
+    pub offcanvas_title: AttrValue,
+
+    /// Container wrapping the navbar content, default [ContainerType::Fluid]. Use a fixed or
+    /// responsive container to align the navbar content with the page content.
+    #[prop_or_default]
+    pub container: ContainerType,
+
+    /// If true, the navbar slides out of view when the page is scrolled down, and back
+    /// when it is scrolled up. The `navbar-hidden` class is set while it is hidden.
+    /// Typically used with a `fixed-top` or `sticky-top` navbar.
+    #[prop_or_default]
+    pub hide_on_scroll: bool,
+
+    /// Search form shown after the items, in the collapsible content
+    #[prop_or_default]
+    pub search: Option<VChild<NavBarSearch>>,
 }
 
 impl Component for NavBar {
@@ -369,20 +548,118 @@ impl Component for NavBar {
             }
         };
 
-        html! {
-            <nav class={classes}>
-                <Container fluid=true>
-                    <button class="navbar-toggler" type="button" data-bs-toggle="collapse" data-bs-target={format!("#{}", props.nav_id.clone())} aria-controls={props.nav_id.clone()} aria-expanded={expanded} aria-label="Toggle navigation">
-                        <span class="navbar-toggler-icon"></span>
-                    </button>
-                    {brand}
-                    <div class="collapse navbar-collapse" id={props.nav_id.clone()}>
-                        <ul class="navbar-nav">
+        let content = if props.offcanvas {
+            let label_id = format!("{}-label", props.nav_id);
+            html! { <>
+                {brand}
+                <button class="navbar-toggler" type="button" data-bs-toggle="offcanvas" data-bs-target={format!("#{}", props.nav_id.clone())} aria-controls={props.nav_id.clone()} aria-label="Toggle navigation">
+                    <span class="navbar-toggler-icon"></span>
+                </button>
+                <div class="offcanvas offcanvas-end" tabindex="-1" id={props.nav_id.clone()} aria-labelledby={label_id.clone()}>
+                    <div class="offcanvas-header">
+                        <h5 class="offcanvas-title" id={label_id}>{props.offcanvas_title.clone()}</h5>
+                        <button type="button" class="btn-close" data-bs-dismiss="offcanvas" aria-label="Close"></button>
+                    </div>
+                    <div class="offcanvas-body">
+                        <ul class={classes!("navbar-nav", props.search.is_some().then_some("flex-grow-1"))}>
                             { for props.children.clone() }
                         </ul>
+                        { props.search.clone() }
                     </div>
-                </Container>
+                </div>
+            </> }
+        } else {
+            html! { <>
+                <button class="navbar-toggler" type="button" data-bs-toggle="collapse" data-bs-target={format!("#{}", props.nav_id.clone())} aria-controls={props.nav_id.clone()} aria-expanded={expanded} aria-label="Toggle navigation">
+                    <span class="navbar-toggler-icon"></span>
+                </button>
+                {brand}
+                <div class="collapse navbar-collapse" id={props.nav_id.clone()}>
+                    <ul class={classes!("navbar-nav", props.search.is_some().then_some("me-auto"))}>
+                        { for props.children.clone() }
+                    </ul>
+                    { props.search.clone() }
+                </div>
+            </> }
+        };
+        let content = props.container.wrap(content);
+
+        if props.hide_on_scroll {
+            return html! {
+                <ScrollHidingNav class={classes}>{content}</ScrollHidingNav>
+            }
+        }
+
+        html! {
+            <nav class={classes}>
+                {content}
             </nav>
         }
     }
-}
\ No newline at end of file
+}
+
+/// Properties of [ScrollHidingNav]
+#[derive(Properties, Clone, PartialEq)]
+struct ScrollHidingNavProps {
+    /// Classes of the navbar
+    class: Classes,
+
+    /// Content of the navbar
+    children: Html,
+}
+
+/// `<nav>` element slid out of view while the page is scrolled down, and back in
+/// when it is scrolled up
+#[function_component]
+fn ScrollHidingNav(props: &ScrollHidingNavProps) -> Html {
+    let node_ref = use_node_ref();
+    // Measured after each render, the navbar is not in the document yet while rendering
+    let height = use_state_eq(|| 0);
+    {
+        let node_ref = node_ref.clone();
+        let height = height.clone();
+        use_effect(move || {
+            if let Some(nav) = node_ref.cast::<HtmlElement>() {
+                height.set(nav.offset_height());
+            }
+        });
+    }
+    let scroll = use_scroll_direction(f64::from(*height));
+    let hidden = scroll.direction == ScrollDirection::Down && scroll.past_offset;
+
+    let mut classes = props.class.clone();
+    let mut css = String::from("transition: transform 0.3s ease-in-out");
+    if hidden {
+        classes.push("navbar-hidden");
+        css.push_str("; transform: translateY(-100%)");
+    }
+
+    html! {
+        <nav class={classes} style={css} ref={node_ref}>
+            { props.children.clone() }
+        </nav>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use yew::LocalServerRenderer;
+
+    use super::*;
+
+    #[function_component]
+    fn HidingNavBar() -> Html {
+        html! {
+            <NavBar nav_id="test-nav" class="navbar-expand-lg" hide_on_scroll={true}>
+                <NavItem text="Home" />
+            </NavBar>
+        }
+    }
+
+    #[tokio::test]
+    async fn server_side_hide_on_scroll() {
+        let html = LocalServerRenderer::<HidingNavBar>::new().hydratable(false).render().await;
+        assert!(html.starts_with("<nav"), "{}", html);
+        assert!(!html.contains("navbar-hidden"), "{}", html);
+    }
+}
@@ -0,0 +1,259 @@
+use yew::prelude::*;
+
+/// Size of a [Pagination]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PaginationSize {
+    /// `.pagination-sm`
+    Small,
+    #[default]
+    Normal,
+    /// `.pagination-lg`
+    Large,
+}
+
+/// Arrow shown by a previous or next [PaginationItem]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaginationArrow {
+    /// `«`, labelled "Previous"
+    Previous,
+    /// `»`, labelled "Next"
+    Next,
+}
+
+/// Entry of a windowed page list, see [pagination_window]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaginationEntry {
+    /// Page number, starting at 1
+    Page(usize),
+    /// Hidden pages, shown as `…`
+    Ellipsis,
+}
+
+/// Windowed list of pages for a [Pagination] with many pages: the first and last
+/// pages, `sibling_count` pages on each side of the `current` page, and ellipses for
+/// the hidden pages.
+///
+/// Pages start at 1. The list always has the same length, `2 * sibling_count + 5`
+/// entries, unless `total` pages fit without ellipsis. An ellipsis never hides a
+/// single page.
+///
+/// ```rust
+/// use yew_bootstrap::component::{pagination_window, PaginationEntry::{Ellipsis, Page}};
+/// assert_eq!(
+///     pagination_window(6, 20, 2),
+///     vec![Page(1), Ellipsis, Page(4), Page(5), Page(6), Page(7), Page(8), Ellipsis, Page(20)]
+/// );
+/// assert_eq!(
+///     pagination_window(2, 20, 1),
+///     vec![Page(1), Page(2), Page(3), Page(4), Page(5), Ellipsis, Page(20)]
+/// );
+/// assert_eq!(pagination_window(3, 4, 1), vec![Page(1), Page(2), Page(3), Page(4)]);
+/// ```
+pub fn pagination_window(current: usize, total: usize, sibling_count: usize) -> Vec<PaginationEntry> {
+    // First, last, current and two ellipses
+    let slots = 2 * sibling_count + 5;
+    if total <= slots {
+        return (1..=total).map(PaginationEntry::Page).collect();
+    }
+
+    let current = current.clamp(1, total);
+    let first_sibling = current.saturating_sub(sibling_count).max(1);
+    let last_sibling = (current + sibling_count).min(total);
+    // An ellipsis replaces at least two pages
+    let left_ellipsis = first_sibling > 3;
+    let right_ellipsis = last_sibling + 2 < total;
+
+    let pages = |range: std::ops::RangeInclusive<usize>| range.map(PaginationEntry::Page);
+    let mut entries = Vec::with_capacity(slots);
+    match (left_ellipsis, right_ellipsis) {
+        (false, _) => {
+            entries.extend(pages(1..=slots - 2));
+            entries.push(PaginationEntry::Ellipsis);
+            entries.push(PaginationEntry::Page(total));
+        }
+        (true, false) => {
+            entries.push(PaginationEntry::Page(1));
+            entries.push(PaginationEntry::Ellipsis);
+            entries.extend(pages(total + 3 - slots..=total));
+        }
+        (true, true) => {
+            entries.push(PaginationEntry::Page(1));
+            entries.push(PaginationEntry::Ellipsis);
+            entries.extend(pages(first_sibling..=last_sibling));
+            entries.push(PaginationEntry::Ellipsis);
+            entries.push(PaginationEntry::Page(total));
+        }
+    }
+    entries
+}
+
+/// # Properties of [Pagination]
+#[derive(Properties, Clone, PartialEq)]
+pub struct PaginationProps {
+    /// Inner items (displayed in the [Pagination])
+    #[prop_or_default]
+    pub children: ChildrenWithProps<PaginationItem>,
+    /// Extra CSS classes of the list, e.g. `justify-content-center`
+    #[prop_or_default]
+    pub class: Classes,
+    /// Size of the items
+    #[prop_or_default]
+    pub size: PaginationSize,
+    /// Accessible label of the navigation, default "Pagination"
+    #[prop_or(AttrValue::from("Pagination"))]
+    pub aria_label: AttrValue,
+}
+
+/// # Pagination component
+/// Navigation between the pages of a series, such as a table, made of [PaginationItem]s.
+///
+/// See [PaginationProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Pagination, PaginationArrow, PaginationItem};
+/// fn test() -> Html {
+///     html! {
+///         <Pagination>
+///             <PaginationItem arrow={PaginationArrow::Previous} href="/page/1" />
+///             <PaginationItem href="/page/1">{"1"}</PaginationItem>
+///             <PaginationItem active=true>{"2"}</PaginationItem>
+///             <PaginationItem href="/page/3">{"3"}</PaginationItem>
+///             <PaginationItem arrow={PaginationArrow::Next} href="/page/3" />
+///         </Pagination>
+///     }
+/// }
+/// ```
+///
+/// With many pages, [pagination_window] lists the pages to show:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{
+///     pagination_window, Pagination, PaginationArrow, PaginationEntry, PaginationItem, PaginationSize,
+/// };
+/// #[function_component]
+/// fn Pages() -> Html {
+///     let total = 20;
+///     let current = use_state(|| 1_usize);
+///     let go_to = |page: usize| {
+///         let current = current.clone();
+///         Callback::from(move |_| current.set(page))
+///     };
+///     html! {
+///         <Pagination size={PaginationSize::Small} class="justify-content-center">
+///             <PaginationItem
+///                 arrow={PaginationArrow::Previous}
+///                 disabled={*current == 1}
+///                 onclick={go_to(current.saturating_sub(1))}
+///             />
+///             { for pagination_window(*current, total, 1).into_iter().map(|entry| match entry {
+///                 PaginationEntry::Page(page) => html_nested! {
+///                     <PaginationItem active={page == *current} onclick={go_to(page)}>
+///                         { page }
+///                     </PaginationItem>
+///                 },
+///                 PaginationEntry::Ellipsis => html_nested! {
+///                     <PaginationItem disabled=true>{"…"}</PaginationItem>
+///                 },
+///             }) }
+///             <PaginationItem
+///                 arrow={PaginationArrow::Next}
+///                 disabled={*current == total}
+///                 onclick={go_to(*current + 1)}
+///             />
+///         </Pagination>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Pagination(props: &PaginationProps) -> Html {
+    let mut classes = classes!("pagination");
+    match props.size {
+        PaginationSize::Small => classes.push("pagination-sm"),
+        PaginationSize::Large => classes.push("pagination-lg"),
+        PaginationSize::Normal => (),
+    }
+    classes.extend(&props.class);
+
+    html! {
+        <nav aria-label={props.aria_label.clone()}>
+            <ul class={classes}>
+                { for props.children.iter() }
+            </ul>
+        </nav>
+    }
+}
+
+/// # Properties for [PaginationItem]
+#[derive(Properties, Clone, PartialEq)]
+pub struct PaginationItemProps {
+    /// Inner components, typically the page number. Ignored with `arrow`.
+    #[prop_or_default]
+    pub children: Children,
+    /// Extra CSS classes to include, in addition to the defaults
+    #[prop_or_default]
+    pub class: Classes,
+    /// Whether this item is the current page
+    #[prop_or_default]
+    pub active: bool,
+    /// Whether this item is disabled
+    #[prop_or_default]
+    pub disabled: bool,
+    /// If set, the item is a previous or next arrow with an accessible label
+    #[prop_or_default]
+    pub arrow: Option<PaginationArrow>,
+    /// URL of the page. Without URL, the item is a button calling `onclick`.
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    /// Event called when the item is clicked
+    #[prop_or_default]
+    pub onclick: Callback<MouseEvent>,
+}
+
+/// # PaginationItem
+/// Page link or button of a [Pagination].
+///
+/// See [PaginationItemProps] for a list of properties, and [Pagination] for examples.
+#[function_component]
+pub fn PaginationItem(props: &PaginationItemProps) -> Html {
+    let mut classes = classes!("page-item");
+    if props.active {
+        classes.push("active");
+    }
+    if props.disabled {
+        classes.push("disabled");
+    }
+    classes.extend(&props.class);
+
+    let (content, aria_label) = match props.arrow {
+        Some(PaginationArrow::Previous) => (html! { <span aria-hidden="true">{"«"}</span> }, Some("Previous")),
+        Some(PaginationArrow::Next) => (html! { <span aria-hidden="true">{"»"}</span> }, Some("Next")),
+        None => (props.children.clone().into(), None),
+    };
+    let aria_current = props.active.then_some("page");
+
+    let link = match &props.href {
+        Some(href) if !props.disabled => html! {
+            <a class="page-link" href={href.clone()} onclick={props.onclick.clone()}
+                aria-label={aria_label} aria-current={aria_current}>
+                { content }
+            </a>
+        },
+        _ if props.active => html! {
+            <span class="page-link" aria-current={aria_current}>{ content }</span>
+        },
+        _ => html! {
+            <button type="button" class="page-link" onclick={props.onclick.clone()}
+                disabled={props.disabled} aria-label={aria_label}>
+                { content }
+            </button>
+        },
+    };
+
+    html! {
+        <li class={classes}>
+            { link }
+        </li>
+    }
+}
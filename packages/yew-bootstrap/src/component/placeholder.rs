@@ -0,0 +1,113 @@
+use yew::prelude::*;
+
+use crate::util::Color;
+
+/// Size of a [Placeholder]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlaceholderSize {
+    /// Extra small, `.placeholder-xs`
+    ExtraSmall,
+    /// Small, `.placeholder-sm`
+    Small,
+    /// Large, `.placeholder-lg`
+    Large,
+}
+
+impl std::fmt::Display for PlaceholderSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlaceholderSize::ExtraSmall => write!(f, "placeholder-xs"),
+            PlaceholderSize::Small => write!(f, "placeholder-sm"),
+            PlaceholderSize::Large => write!(f, "placeholder-lg"),
+        }
+    }
+}
+
+/// Animation of a [Placeholder]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PlaceholderAnimation {
+    /// Not animated
+    #[default]
+    None,
+    /// Pulsing opacity, `.placeholder-glow`
+    Glow,
+    /// Wave sweeping across, `.placeholder-wave`
+    Wave,
+}
+
+impl PlaceholderAnimation {
+    fn class(&self) -> Option<&'static str> {
+        match self {
+            PlaceholderAnimation::None => None,
+            PlaceholderAnimation::Glow => Some("placeholder-glow"),
+            PlaceholderAnimation::Wave => Some("placeholder-wave"),
+        }
+    }
+}
+
+/// # Properties of [Placeholder]
+#[derive(Properties, Clone, PartialEq)]
+pub struct PlaceholderProps {
+    /// CSS class
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Width in grid columns, from 1 to 12. Default 12.
+    #[prop_or(12)]
+    pub width: u8,
+
+    /// Size, default is the current line height
+    #[prop_or_default]
+    pub size: Option<PlaceholderSize>,
+
+    /// Background color, default is the current text color
+    #[prop_or_default]
+    pub style: Option<Color>,
+
+    /// Animation, default none
+    #[prop_or_default]
+    pub animation: PlaceholderAnimation,
+}
+
+/// # Placeholder component
+/// Loading placeholder for a line of content, hidden from assistive technologies.
+///
+/// See [PlaceholderProps] for a listing of properties.
+///
+/// See [bootstrap docs](https://getbootstrap.com/docs/5.3/components/placeholders/) for
+/// a full demo of placeholders.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Placeholder, PlaceholderAnimation, PlaceholderSize};
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html!{
+///         <>
+///             <Placeholder width={6} animation={PlaceholderAnimation::Glow} />
+///             <Placeholder width={4} size={PlaceholderSize::Large} style={Color::Secondary} />
+///         </>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Placeholder(props: &PlaceholderProps) -> Html {
+    let placeholder = html! {
+        <span
+            class={classes!(
+                "placeholder",
+                format!("col-{}", props.width.clamp(1, 12)),
+                props.size.map(|size| size.to_string()),
+                props.style.as_ref().map(|style| format!("bg-{}", style)),
+                props.class.clone(),
+            )}
+            aria-hidden="true"
+        />
+    };
+
+    match props.animation.class() {
+        Some(animation) => html! { <span class={animation}>{ placeholder }</span> },
+        None => placeholder,
+    }
+}
@@ -0,0 +1,201 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::util::{Color, Size};
+
+/// # Properties of [ProgressBar]
+#[derive(Properties, Clone, PartialEq)]
+pub struct ProgressBarProps {
+    /// CSS class
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Current value, between `min` and `max`
+    #[prop_or_default]
+    pub value: f64,
+
+    /// Minimum value, default 0
+    #[prop_or(0.0)]
+    pub min: f64,
+
+    /// Maximum value, default 100
+    #[prop_or(100.0)]
+    pub max: f64,
+
+    /// Color of the bar, default is the Bootstrap primary color
+    #[prop_or_default]
+    pub style: Option<Color>,
+
+    /// Striped bar
+    #[prop_or_default]
+    pub striped: bool,
+
+    /// Animated stripes, implies `striped`
+    #[prop_or_default]
+    pub animated: bool,
+
+    /// Accessible label of the bar, useful for each segment of a stacked [Progress]
+    #[prop_or_default]
+    pub aria_label: Option<AttrValue>,
+
+    /// Optional content displayed in the bar, typically the percentage
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// # Progress bar
+/// Bar of a [Progress], or one of its segments when it has several.
+///
+/// See [ProgressBarProps] for a listing of properties, and [Progress] for examples.
+#[function_component]
+pub fn ProgressBar(props: &ProgressBarProps) -> Html {
+    let range = props.max - props.min;
+    let percent = if range > 0.0 {
+        ((props.value - props.min) / range * 100.0).clamp(0.0, 100.0)
+    } else {
+        0.0
+    };
+
+    let mut classes = classes!("progress-bar");
+    if let Some(style) = &props.style {
+        classes.push(format!("bg-{}", style));
+    }
+    if props.striped || props.animated {
+        classes.push("progress-bar-striped");
+    }
+    if props.animated {
+        classes.push("progress-bar-animated");
+    }
+    classes.extend(props.class.clone());
+
+    html! {
+        <div
+            class={classes}
+            role="progressbar"
+            style={format!("width: {}%", percent)}
+            aria-valuenow={props.value.to_string()}
+            aria-valuemin={props.min.to_string()}
+            aria-valuemax={props.max.to_string()}
+            aria-label={props.aria_label.clone()}
+        >
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// # Properties of [Progress]
+#[derive(Properties, Clone, PartialEq)]
+pub struct ProgressProps {
+    /// CSS class
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Current value, between `min` and `max`. Ignored with children.
+    #[prop_or_default]
+    pub value: f64,
+
+    /// Minimum value, default 0. Also used by the children.
+    #[prop_or(0.0)]
+    pub min: f64,
+
+    /// Maximum value, default 100. Also used by the children.
+    #[prop_or(100.0)]
+    pub max: f64,
+
+    /// Color of the bar, default is the Bootstrap primary color. Ignored with children.
+    #[prop_or_default]
+    pub style: Option<Color>,
+
+    /// Striped bar. Ignored with children.
+    #[prop_or_default]
+    pub striped: bool,
+
+    /// Animated stripes, implies `striped`. Ignored with children.
+    #[prop_or_default]
+    pub animated: bool,
+
+    /// Accessible label of the bar
+    #[prop_or_default]
+    pub aria_label: Option<AttrValue>,
+
+    /// Optional height of the progress, e.g. `Size::Px(4)`
+    #[prop_or_default]
+    pub height: Option<Size>,
+
+    /// Segments of a stacked progress. If empty, a single bar is shown with `value`.
+    #[prop_or_default]
+    pub children: ChildrenWithProps<ProgressBar>,
+}
+
+/// # Progress component
+/// Shows the progress of an operation in a bar, or several stacked bars.
+///
+/// See [ProgressProps] for a listing of properties.
+///
+/// See [bootstrap docs](https://getbootstrap.com/docs/5.1/components/progress/) for a
+/// full demo of progress bars.
+///
+/// ## Examples
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Progress;
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html!{
+///         <>
+///             <Progress value={25.0} aria_label="Upload" />
+///             <Progress value={3.0} max={4.0} style={Color::Success} striped={true} animated={true} />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// Several [ProgressBar]s are stacked in a single progress, using its `min` and `max`:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Progress, ProgressBar};
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html!{
+///         <Progress>
+///             <ProgressBar value={15.0} aria_label="Segment one" />
+///             <ProgressBar value={30.0} style={Color::Success} aria_label="Segment two" />
+///             <ProgressBar value={20.0} style={Color::Info} aria_label="Segment three" />
+///         </Progress>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Progress(props: &ProgressProps) -> Html {
+    let mut classes = classes!("progress");
+    classes.extend(props.class.clone());
+    let style = props.height.as_ref().map(|height| format!("height: {}", height));
+
+    if props.children.is_empty() {
+        return html! {
+            <div class={classes} style={style}>
+                <ProgressBar
+                    value={props.value}
+                    min={props.min}
+                    max={props.max}
+                    style={props.style.clone()}
+                    striped={props.striped}
+                    animated={props.animated}
+                    aria_label={props.aria_label.clone()}
+                />
+            </div>
+        };
+    }
+
+    html! {
+        <div class={classes} style={style} aria-label={props.aria_label.clone()}>
+            { for props.children.iter().map(|mut child| {
+                let child_props = Rc::make_mut(&mut child.props);
+                child_props.min = props.min;
+                child_props.max = props.max;
+                child
+            }) }
+        </div>
+    }
+}
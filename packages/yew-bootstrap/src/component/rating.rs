@@ -0,0 +1,147 @@
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+
+use crate::icons::BI;
+use crate::util::Color;
+
+/// # Properties of [Rating]
+#[derive(Properties, Clone, PartialEq)]
+pub struct RatingProps {
+    /// Current rating, from 0 (not rated) to `max`
+    #[prop_or_default]
+    pub value: u8,
+
+    /// Maximum rating, default 5
+    #[prop_or(5)]
+    pub max: u8,
+
+    /// If true, the rating is only displayed and cannot be changed
+    #[prop_or_default]
+    pub readonly: bool,
+
+    /// Accessible label of the rating, default "Rating"
+    #[prop_or(AttrValue::from("Rating"))]
+    pub label: AttrValue,
+
+    /// Color of the stars, default [Color::Warning]
+    #[prop_or(Color::Warning)]
+    pub style: Color,
+
+    /// CSS class
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Called with the new rating when it is changed by the user
+    #[prop_or_default]
+    pub on_change: Callback<u8>,
+}
+
+/// # Rating
+/// Star rating input, using Bootstrap Icons for the stars.
+///
+/// The stars are exposed to assistive technologies as a group of radios. Only the
+/// selected star is in the tab order; arrow keys change the rating, Home and End
+/// select the lowest and highest ratings.
+///
+/// See [RatingProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Rating;
+/// #[function_component]
+/// fn Review() -> Html {
+///     let rating = use_state(|| 3);
+///     let on_change = {
+///         let rating = rating.clone();
+///         Callback::from(move |value| rating.set(value))
+///     };
+///     html!{
+///         <>
+///             <Rating value={*rating} on_change={on_change} label="Your rating" />
+///             <Rating value={4} readonly={true} label="Average rating" />
+///         </>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Rating(props: &RatingProps) -> Html {
+    let node_ref = use_node_ref();
+
+    let mut classes = classes!("d-inline-flex", format!("text-{}", props.style));
+    classes.push(props.class.clone());
+
+    let star = |index: u8| if index <= props.value { BI::STAR_FILL } else { BI::STAR };
+
+    if props.readonly {
+        return html! {
+            <span
+                class={classes}
+                role="img"
+                aria-label={format!("{}: {} out of {}", props.label, props.value, props.max)}
+            >
+                { for (1..=props.max).map(|index| html! { <span class="me-1">{ star(index) }</span> }) }
+            </span>
+        }
+    }
+
+    let onkeydown = {
+        let node_ref = node_ref.clone();
+        let on_change = props.on_change.clone();
+        let (value, max) = (props.value, props.max);
+        Callback::from(move |event: KeyboardEvent| {
+            // No star to select
+            if max == 0 {
+                return;
+            }
+            let new_value = match &event.key()[..] {
+                "ArrowRight" | "ArrowUp" => value.saturating_add(1),
+                "ArrowLeft" | "ArrowDown" => value.saturating_sub(1),
+                "Home" => 1,
+                "End" => max,
+                _ => return,
+            };
+            let new_value = new_value.clamp(1, max);
+            event.prevent_default();
+            on_change.emit(new_value);
+            // Move the focus to the newly selected star
+            let star = node_ref.cast::<Element>()
+                .and_then(|group| group.children().item(u32::from(new_value).checked_sub(1)?))
+                .and_then(|star| star.dyn_into::<HtmlElement>().ok());
+            if let Some(star) = star {
+                let _ = star.focus();
+            }
+        })
+    };
+
+    html! {
+        <div
+            class={classes}
+            role="radiogroup"
+            aria-label={props.label.clone()}
+            onkeydown={onkeydown}
+            ref={node_ref}
+        >
+            { for (1..=props.max).map(|index| {
+                let checked = index == props.value;
+                // Roving focus: the selected star, or the first one when not rated
+                let focusable = checked || (props.value == 0 && index == 1);
+                let onclick = props.on_change.reform(move |_: MouseEvent| index);
+                html! {
+                    <button
+                        type="button"
+                        class={classes!("btn", "btn-link", "p-0", "me-1", format!("text-{}", props.style))}
+                        role="radio"
+                        aria-checked={checked.to_string()}
+                        aria-label={format!("{} out of {}", index, props.max)}
+                        tabindex={if focusable { "0" } else { "-1" }}
+                        onclick={onclick}
+                    >
+                        { star(index) }
+                    </button>
+                }
+            }) }
+        </div>
+    }
+}
@@ -1,4 +1,5 @@
 use super::Column;
+use crate::util::{AlignItems, JustifyContent};
 use yew::prelude::*;
 
 /// # Row container
@@ -19,6 +20,21 @@ use yew::prelude::*;
 ///     }
 /// }
 /// ```
+///
+/// Columns can be aligned inside the row:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Column, Row};
+/// use yew_bootstrap::util::{AlignItems, JustifyContent};
+/// fn test() -> Html {
+///     html!{
+///         <Row align_items={AlignItems::Center} justify_content={JustifyContent::Between}>
+///             <Column lg=4><p>{ "Left" }</p></Column>
+///             <Column lg=4><p>{ "Right" }</p></Column>
+///         </Row>
+///     }
+/// }
+/// ```
 pub struct Row {}
 
 /// # Properties for [Row]
@@ -32,6 +48,14 @@ pub struct RowProps {
     #[prop_or_default]
     pub onclick: Callback<MouseEvent>,
 
+    /// Vertical alignment of the columns
+    #[prop_or_default]
+    pub align_items: Option<AlignItems>,
+
+    /// Horizontal alignment of the columns
+    #[prop_or_default]
+    pub justify_content: Option<JustifyContent>,
+
     /// Children of type [crate::component::Column]
     #[prop_or_default]
     pub children: ChildrenWithProps<Column>,
@@ -49,6 +73,12 @@ impl Component for Row {
         let props = ctx.props();
         let mut classes = Classes::new();
         classes.push("row");
+        if let Some(align_items) = &props.align_items {
+            classes.push(align_items.to_string());
+        }
+        if let Some(justify_content) = &props.justify_content {
+            classes.push(justify_content.to_string());
+        }
         classes.push(props.class.clone());
 
         html! {
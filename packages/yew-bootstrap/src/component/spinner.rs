@@ -1,6 +1,6 @@
 use yew::prelude::*;
 
-use crate::util::Color;
+use crate::util::{prefers_reduced_motion, Color, Size};
 
 /// # Spinner component
 /// Used alongside [crate::util::Color] to create Spinner components
@@ -20,7 +20,56 @@ use crate::util::Color;
 ///     }
 /// }
 /// ```
-pub struct Spinner {}
+///
+/// Without children, the spinner is announced with its `label`, "Loading..." by default:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Spinner;
+/// use yew_bootstrap::util::Color;
+/// fn test() -> Html {
+///     html!{
+///         <>
+///             <Spinner style={Color::Success} />
+///             <Spinner style={Color::Danger} grow={true} label="Saving..." />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// Custom sizes can be set with `width` and `height`:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Spinner;
+/// use yew_bootstrap::util::Size;
+/// fn test() -> Html {
+///     html!{
+///         <Spinner width={Size::Rem(3.0)} height={Size::Rem(3.0)}>
+///             {"Loading..."}
+///         </Spinner>
+///     }
+/// }
+/// ```
+///
+/// Bootstrap only slows the animation down when the user prefers reduced motion.
+/// With `respect_reduced_motion`, the spinner is shown as a static indicator instead:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Spinner;
+/// fn test() -> Html {
+///     html!{
+///         <Spinner respect_reduced_motion={true}>
+///             {"Loading..."}
+///         </Spinner>
+///     }
+/// }
+/// ```
+pub struct Spinner {
+    reduced_motion: bool,
+}
+
 
 /// # Properties of [Spinner]
 #[derive(Properties, Clone, PartialEq)]
@@ -29,10 +78,15 @@ pub struct SpinnerProps {
     #[prop_or_default]
     pub class: String,
 
-    /// Inner components (visually hidden text)
+    /// Inner components (visually hidden text), replacing `label`
     #[prop_or_default]
     pub children: Children,
 
+    /// Visually hidden text read by assistive technologies, used when there are no
+    /// children. Default "Loading...".
+    #[prop_or(AttrValue::from("Loading..."))]
+    pub label: AttrValue,
+
     /// Color style, default [Color::Primary]
     #[prop_or(Color::Primary)]
     pub style: Color,
@@ -44,14 +98,33 @@ pub struct SpinnerProps {
     /// Small size style, default false
     #[prop_or_default]
     pub small: bool,
+
+    /// Custom width, set as inline style
+    #[prop_or_default]
+    pub width: Option<Size>,
+
+    /// Custom height, set as inline style
+    #[prop_or_default]
+    pub height: Option<Size>,
+
+    /// If true and the user prefers reduced motion, the spinner is not animated
+    #[prop_or_default]
+    pub respect_reduced_motion: bool,
 }
 
 impl Component for Spinner {
     type Message = ();
     type Properties = SpinnerProps;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {}
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            reduced_motion: ctx.props().respect_reduced_motion && prefers_reduced_motion(),
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.reduced_motion = ctx.props().respect_reduced_motion && prefers_reduced_motion();
+        true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
@@ -73,10 +146,30 @@ impl Component for Spinner {
         classes.push(format!("text-{}", props.style));
         classes.push(props.class.clone());
 
+        let mut css = Vec::new();
+        if let Some(width) = &props.width {
+            css.push(format!("width: {}", width));
+        }
+        if let Some(height) = &props.height {
+            css.push(format!("height: {}", height));
+        }
+        if self.reduced_motion {
+            css.push("animation: none".to_string());
+            if props.grow {
+                // A grow spinner is transparent until animated
+                css.push("opacity: 0.5".to_string());
+            }
+        }
+        let css = (!css.is_empty()).then(|| css.join("; "));
+
         html! {
-            <div class={classes} role="status">
+            <div class={classes} role="status" style={css}>
                 <span class="visually-hidden">
-                    { for props.children.iter() }
+                    if props.children.is_empty() {
+                        { props.label.clone() }
+                    } else {
+                        { for props.children.iter() }
+                    }
                 </span>
             </div>
         }
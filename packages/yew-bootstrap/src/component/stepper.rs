@@ -0,0 +1,163 @@
+use yew::prelude::*;
+
+use super::{Badge, Button, ListGroup, ListGroupItem, SizeTrigger};
+use crate::util::Color;
+
+/// # Properties of [Stepper]
+#[derive(Properties, Clone, PartialEq)]
+pub struct StepperProps {
+    /// Labels of the steps, in order
+    pub steps: Vec<AttrValue>,
+
+    /// Index of the current step, starting at 0
+    #[prop_or_default]
+    pub current: usize,
+
+    /// Whether the current step is valid. The user can only move to the next step when true.
+    #[prop_or(true)]
+    pub valid: bool,
+
+    /// Called with the index of the step to move to
+    #[prop_or_default]
+    pub on_step: Callback<usize>,
+
+    /// Called when the finish button is clicked on the last step
+    #[prop_or_default]
+    pub on_finish: Callback<()>,
+
+    /// Content of the current step
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Text of the back button
+    #[prop_or(AttrValue::from("Back"))]
+    pub back_text: AttrValue,
+
+    /// Text of the next button
+    #[prop_or(AttrValue::from("Next"))]
+    pub next_text: AttrValue,
+
+    /// Text of the next button on the last step
+    #[prop_or(AttrValue::from("Finish"))]
+    pub finish_text: AttrValue,
+}
+
+/// # Stepper
+/// Multi-step form or wizard. Shows the numbered list of steps, the content
+/// of the current step (its children), and back/next buttons.
+///
+/// The stepper is controlled: the parent owns the current step, and updates it when
+/// `on_step` is called. Previous steps can be selected again, while following steps
+/// are disabled. Moving forward is only possible when `valid` is true.
+///
+/// See [StepperProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Stepper;
+/// #[function_component]
+/// fn Test() -> Html {
+///     let current = use_state(|| 0);
+///     let steps = vec![
+///         AttrValue::from("Account"),
+///         AttrValue::from("Address"),
+///         AttrValue::from("Confirm"),
+///     ];
+///     let on_step = {
+///         let current = current.clone();
+///         Callback::from(move |step| current.set(step))
+///     };
+///     html!{
+///         <Stepper steps={steps} current={*current} on_step={on_step}>
+///             <p>{format!("Content of step {}", *current + 1)}</p>
+///         </Stepper>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Stepper(props: &StepperProps) -> Html {
+    let last = props.steps.len().saturating_sub(1);
+    let current = props.current.min(last);
+
+    let steps = props.steps.iter().enumerate().map(|(index, label)| {
+        let (style, onclick) = if index < current {
+            (Color::Success, props.on_step.reform(move |_: MouseEvent| index))
+        } else {
+            (Color::Secondary, Callback::noop())
+        };
+        html_nested! {
+            <ListGroupItem
+                active={index == current}
+                disabled={index > current}
+                action={index < current}
+                onclick={onclick}
+            >
+                <Badge pill={true} style={style} class="me-2">{index + 1}</Badge>
+                { label.clone() }
+            </ListGroupItem>
+        }
+    });
+
+    let on_back = props.on_step.reform(move |_: MouseEvent| current.saturating_sub(1));
+    let on_next = if current == last {
+        props.on_finish.reform(|_: MouseEvent| ())
+    } else {
+        props.on_step.reform(move |_: MouseEvent| current + 1)
+    };
+    let next_text = if current == last { &props.finish_text } else { &props.next_text };
+
+    html! {
+        <div class={props.class.clone()}>
+            <ListGroup horizontal={SizeTrigger::Always} class="mb-3">
+                { for steps }
+            </ListGroup>
+            <div class="mb-3">
+                { for props.children.iter() }
+            </div>
+            <div class="d-flex justify-content-between">
+                <Button style={Color::Secondary} outline={true} disabled={current == 0} onclick={on_back}>
+                    { props.back_text.clone() }
+                </Button>
+                <Button style={Color::Primary} disabled={!props.valid} onclick={on_next}>
+                    { next_text.clone() }
+                </Button>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use yew::LocalServerRenderer;
+
+    use super::*;
+
+    #[function_component]
+    fn StepperForm() -> Html {
+        let steps = vec![AttrValue::from("Account"), AttrValue::from("Address"), AttrValue::from("Confirm")];
+        html! {
+            <form>
+                <Stepper steps={steps} current={1} />
+            </form>
+        }
+    }
+
+    // Buttons without `type="button"` would submit the form
+    #[tokio::test]
+    async fn buttons_do_not_submit() {
+        let html = LocalServerRenderer::<StepperForm>::new().hydratable(false).render().await;
+        let buttons: Vec<&str> = html.split("<button").skip(1)
+            .map(|button| &button[..button.find('>').unwrap()])
+            .collect();
+        // Previous step, back and next
+        assert_eq!(buttons.len(), 3, "{}", html);
+        for button in buttons {
+            assert!(button.contains(r#"type="button""#), "{}", button);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use gloo::timers::callback::Timeout;
+use yew::prelude::*;
+
+use crate::util::{ArrangeX, ArrangeY, Placement, Position, ZIndex};
+
+/// # Properties of [ToastContainer]
+#[derive(Properties, Clone, PartialEq)]
+pub struct ToastContainerProps {
+    /// CSS class
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Position of the container, default [Position::Fixed] to stay in the corner of the
+    /// viewport. Use [Position::Absolute] to place it in a `position-relative` parent.
+    #[prop_or(Position::Fixed)]
+    pub position: Position,
+
+    /// Horizontal placement, default [ArrangeX::End0]. `Start50` centers the toasts.
+    #[prop_or(ArrangeX::End0)]
+    pub x: ArrangeX,
+
+    /// Vertical placement, default [ArrangeY::Bottom0]. `Top50` centers the toasts.
+    #[prop_or(ArrangeY::Bottom0)]
+    pub y: ArrangeY,
+
+    /// The [Toast] instances, stacked with spacing between them
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// # Toast container
+/// Places [Toast] instances in a corner (or the middle of an edge) of the viewport, and
+/// stacks them.
+///
+/// See [ToastContainerProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Toast, ToastContainer};
+/// use yew_bootstrap::util::{ArrangeX, ArrangeY};
+/// #[function_component]
+/// fn Notifications() -> Html {
+///     let show = use_state(|| true);
+///     let on_close = {
+///         let show = show.clone();
+///         Callback::from(move |_| show.set(false))
+///     };
+///     html!{
+///         <ToastContainer x={ArrangeX::End0} y={ArrangeY::Top0}>
+///             <Toast
+///                 show={*show}
+///                 on_close={on_close}
+///                 auto_hide={5000}
+///                 header={html!{ <strong class="me-auto">{"Upload"}</strong> }}
+///             >
+///                 {"The file was uploaded"}
+///             </Toast>
+///         </ToastContainer>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn ToastContainer(props: &ToastContainerProps) -> Html {
+    let mut placement = Placement::new(props.position.clone()).x(props.x.clone()).y(props.y.clone());
+    // Center on the middle offsets
+    placement = match (&props.x, &props.y) {
+        (ArrangeX::Start50 | ArrangeX::End50, ArrangeY::Top50 | ArrangeY::Bottom50) => placement.translate_middle(),
+        (ArrangeX::Start50 | ArrangeX::End50, _) => placement.translate_middle_x(),
+        (_, ArrangeY::Top50 | ArrangeY::Bottom50) => placement.translate_middle_y(),
+        _ => placement,
+    };
+
+    let mut classes = classes!("toast-container", "p-3");
+    classes.extend(Classes::from(placement));
+    classes.extend(props.class.clone());
+
+    html! {
+        <div class={classes} style={ZIndex::Toast.css()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// # Properties of [Toast]
+#[derive(Properties, Clone, PartialEq)]
+pub struct ToastProps {
+    /// CSS class
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// If true, the toast is visible
+    #[prop_or_default]
+    pub show: bool,
+
+    /// Called when the close button is clicked, or when the `auto_hide` delay elapsed.
+    /// The parent should then set `show` to false.
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+
+    /// Optional header content, typically a title in `<strong class="me-auto">`. The
+    /// close button is placed in the header, or next to the body without header.
+    #[prop_or_default]
+    pub header: Option<Html>,
+
+    /// If set, `on_close` is called this delay after the toast is shown, in milliseconds
+    #[prop_or_default]
+    pub auto_hide: Option<u32>,
+
+    /// Body of the toast
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// # Toast
+/// Lightweight notification, usually placed in a [ToastContainer]. The visibility is
+/// controlled by the parent with `show`.
+///
+/// See [ToastProps] for a listing of properties, and [ToastContainer] for an example.
+///
+/// A toast without header shows its close button next to the body:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Toast;
+/// fn test() -> Html {
+///     html!{
+///         <Toast show={true} class="position-static">{"Hello, world!"}</Toast>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Toast(props: &ToastProps) -> Html {
+    {
+        let on_close = props.on_close.clone();
+        use_effect_with((props.show, props.auto_hide), move |(show, auto_hide)| {
+            let timeout = auto_hide
+                .filter(|_| *show)
+                .map(|delay| Timeout::new(delay, move || on_close.emit(())));
+            move || drop(timeout)
+        });
+    }
+
+    // Bootstrap hides a `.toast` without `.show`
+    let mut classes = classes!("toast", "fade");
+    if props.show {
+        classes.push("show");
+    }
+    classes.extend(props.class.clone());
+
+    let close = html! {
+        <button
+            type="button"
+            class={classes!("btn-close", props.header.is_none().then_some("me-2 m-auto"))}
+            aria-label="Close"
+            onclick={props.on_close.reform(|_| ())}
+        ></button>
+    };
+
+    html! {
+        <div class={classes} role="alert" aria-live="assertive" aria-atomic="true">
+            if let Some(header) = &props.header {
+                <div class="toast-header">
+                    { header.clone() }
+                    { close }
+                </div>
+                <div class="toast-body">
+                    { for props.children.iter() }
+                </div>
+            } else {
+                <div class="d-flex">
+                    <div class="toast-body">
+                        { for props.children.iter() }
+                    </div>
+                    { close }
+                </div>
+            }
+        </div>
+    }
+}
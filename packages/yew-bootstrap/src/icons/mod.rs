@@ -133,7 +133,7 @@ impl PartialEq for BI {
         // Invariant: No two strings at different pointers are equal,
         // Invariant: this is guaranteed due to the fact that it's not possible to create new.
         // Performance hack: Only check those.
-        self.0.as_ptr() as usize == other.0.as_ptr() as usize && self.0.len() == other.0.len()
+        core::ptr::eq(self.0.as_ptr(), other.0.as_ptr()) && self.0.len() == other.0.len()
     }
 }
 
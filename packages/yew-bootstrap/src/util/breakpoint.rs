@@ -0,0 +1,62 @@
+use std::fmt;
+
+/// # Breakpoints
+/// Bootstrap responsive breakpoints, shared by all the responsive properties.
+///
+/// The [Display](fmt::Display) output is the infix used in the responsive classes,
+/// `-` for [Breakpoint::Xs] which has no infix, so the same format works at all
+/// breakpoints. [Breakpoint::suffix] is used for classes ending with the breakpoint.
+///
+/// ```rust
+/// use yew_bootstrap::util::Breakpoint;
+/// assert_eq!(format!("d{}none", Breakpoint::Md), "d-md-none");
+/// assert_eq!(format!("d{}none", Breakpoint::Xs), "d-none");
+/// assert_eq!(format!("container{}", Breakpoint::Lg.suffix()), "container-lg");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// Extra small, below 576px. Classes without breakpoint apply from here.
+    #[default]
+    Xs,
+    /// Small, 576px and up
+    Sm,
+    /// Medium, 768px and up
+    Md,
+    /// Large, 992px and up
+    Lg,
+    /// Extra large, 1200px and up
+    Xl,
+    /// Extra extra large, 1400px and up
+    Xxl,
+}
+
+impl Breakpoint {
+    /// Abbreviation of the breakpoint, empty for [Breakpoint::Xs]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Breakpoint::Xs => "",
+            Breakpoint::Sm => "sm",
+            Breakpoint::Md => "md",
+            Breakpoint::Lg => "lg",
+            Breakpoint::Xl => "xl",
+            Breakpoint::Xxl => "xxl",
+        }
+    }
+
+    /// Suffix for classes ending with the breakpoint (`-md`), empty for [Breakpoint::Xs]
+    pub fn suffix(&self) -> String {
+        match self {
+            Breakpoint::Xs => String::new(),
+            _ => format!("-{}", self.name()),
+        }
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Breakpoint::Xs => write!(f, "-"),
+            _ => write!(f, "-{}-", self.name()),
+        }
+    }
+}
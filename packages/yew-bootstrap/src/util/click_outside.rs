@@ -0,0 +1,48 @@
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::{MouseEvent, Node};
+use yew::prelude::*;
+
+/// # Click outside hook
+/// Calls `callback` when the user clicks anywhere in the document outside of the element
+/// referenced by `node_ref` (and its children). Nothing is called while `node_ref` is not
+/// attached to an element.
+///
+/// The document listener is removed when the component is unmounted.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::util::use_click_outside;
+/// #[function_component]
+/// fn Menu() -> Html {
+///     let node_ref = use_node_ref();
+///     let open = use_state(|| true);
+///     {
+///         let open = open.clone();
+///         use_click_outside(node_ref.clone(), Callback::from(move |_| open.set(false)));
+///     }
+///     html!{
+///         <div ref={node_ref}>
+///             if *open { {"Click outside to close"} }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_click_outside(node_ref: NodeRef, callback: Callback<MouseEvent>) {
+    use_effect_with((node_ref, callback), |(node_ref, callback)| {
+        let node_ref = node_ref.clone();
+        let callback = callback.clone();
+        let listener = EventListener::new(&gloo::utils::document(), "click", move |event| {
+            let Some(element) = node_ref.get() else {
+                return;
+            };
+            let target = event.target().and_then(|target| target.dyn_into::<Node>().ok());
+            if !element.contains(target.as_ref()) {
+                callback.emit(event.clone().unchecked_into::<MouseEvent>());
+            }
+        });
+        move || drop(listener)
+    });
+}
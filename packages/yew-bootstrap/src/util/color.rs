@@ -1,5 +1,7 @@
 use std::fmt;
 
+use yew::Classes;
+
 /// # Colors
 /// Bootstrap colors for buttons, links, etc.
 #[derive(Clone, PartialEq, Eq)]
@@ -15,6 +17,26 @@ pub enum Color {
     Link,
 }
 
+impl Color {
+    /// Color of the text that stays readable on a background of this color:
+    /// [Color::Dark] for light backgrounds (`warning`, `info`, `light`),
+    /// [Color::Light] otherwise. `None` for [Color::Link], which has no background.
+    ///
+    /// ```rust
+    /// use yew_bootstrap::util::Color;
+    /// assert!(Color::Warning.contrasting_text() == Some(Color::Dark));
+    /// assert!(Color::Primary.contrasting_text() == Some(Color::Light));
+    /// assert!(Color::Link.contrasting_text().is_none());
+    /// ```
+    pub fn contrasting_text(&self) -> Option<Color> {
+        match self {
+            Color::Link => None,
+            Color::Info | Color::Warning | Color::Light => Some(Color::Dark),
+            _ => Some(Color::Light),
+        }
+    }
+}
+
 impl fmt::Display for Color {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
@@ -30,3 +52,140 @@ impl fmt::Display for Color {
         }
     }
 }
+
+/// # Text colors
+/// Bootstrap text color tokens, used with the `text-*` classes. This covers the
+/// theme [Color]s, plus the tokens only available for text.
+///
+/// `BodySecondary`, `BodyTertiary` and `BodyEmphasis` require Bootstrap 5.3 or later.
+///
+/// ```rust
+/// use yew_bootstrap::util::{Color, TextColor};
+/// assert_eq!(format!("text-{}", TextColor::Muted), "text-muted");
+/// assert_eq!(format!("text-{}", TextColor::from(Color::Danger)), "text-danger");
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub enum TextColor {
+    /// One of the theme colors
+    Theme(Color),
+    Body,
+    BodySecondary,
+    BodyTertiary,
+    BodyEmphasis,
+    Muted,
+    White,
+    Black,
+    White50,
+    Black50,
+}
+
+impl From<Color> for TextColor {
+    fn from(color: Color) -> Self {
+        TextColor::Theme(color)
+    }
+}
+
+impl fmt::Display for TextColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TextColor::Theme(color) => write!(f, "{}", color),
+            TextColor::Body => write!(f, "body"),
+            TextColor::BodySecondary => write!(f, "body-secondary"),
+            TextColor::BodyTertiary => write!(f, "body-tertiary"),
+            TextColor::BodyEmphasis => write!(f, "body-emphasis"),
+            TextColor::Muted => write!(f, "muted"),
+            TextColor::White => write!(f, "white"),
+            TextColor::Black => write!(f, "black"),
+            TextColor::White50 => write!(f, "white-50"),
+            TextColor::Black50 => write!(f, "black-50"),
+        }
+    }
+}
+
+/// # Opacity levels
+/// Opacity of a [ColorOpacity], used with the `bg-opacity-*` and `text-opacity-*`
+/// classes of Bootstrap 5.1 or later.
+///
+/// `Percent10` is only available for backgrounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Opacity {
+    Percent10,
+    Percent25,
+    Percent50,
+    Percent75,
+    Percent100,
+}
+
+impl fmt::Display for Opacity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Opacity::Percent10 => write!(f, "10"),
+            Opacity::Percent25 => write!(f, "25"),
+            Opacity::Percent50 => write!(f, "50"),
+            Opacity::Percent75 => write!(f, "75"),
+            Opacity::Percent100 => write!(f, "100"),
+        }
+    }
+}
+
+/// # Color with opacity builder
+/// Combines a background [Color] or a [TextColor] with an [Opacity], to build the
+/// Bootstrap color and opacity classes. Requires Bootstrap 5.1 or later.
+///
+/// Combinations without Bootstrap classes are rejected: [Color::Link] has no `bg-link`
+/// or `text-link` class, and there is no `text-opacity-10`.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::util::{Color, ColorOpacity, Opacity, TextColor};
+/// fn highlight() -> Option<Classes> {
+///     Some(ColorOpacity::background(Color::Primary)?.opacity(Opacity::Percent25)?.into())
+/// }
+/// assert_eq!(highlight().unwrap().to_string(), "bg-primary bg-opacity-25");
+/// fn faded() -> Option<Classes> {
+///     Some(ColorOpacity::text(TextColor::Body)?.opacity(Opacity::Percent50)?.into())
+/// }
+/// assert_eq!(faded().unwrap().to_string(), "text-body text-opacity-50");
+/// assert!(ColorOpacity::background(Color::Link).is_none());
+/// assert!(ColorOpacity::text(TextColor::Body).and_then(|text| text.opacity(Opacity::Percent10)).is_none());
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct ColorOpacity {
+    prefix: &'static str,
+    color: TextColor,
+    opacity: Option<Opacity>,
+}
+
+impl ColorOpacity {
+    /// Background color (`bg-*`), fully opaque. `None` for [Color::Link].
+    pub fn background(color: Color) -> Option<Self> {
+        (color != Color::Link).then(|| Self { prefix: "bg", color: color.into(), opacity: None })
+    }
+
+    /// Text color (`text-*`), fully opaque. `None` for [Color::Link].
+    pub fn text(color: impl Into<TextColor>) -> Option<Self> {
+        let color = color.into();
+        (color != TextColor::Theme(Color::Link)).then_some(Self { prefix: "text", color, opacity: None })
+    }
+
+    /// Opacity of the color (`bg-opacity-*` or `text-opacity-*`). `None` for
+    /// [Opacity::Percent10] on a text color.
+    pub fn opacity(mut self, opacity: Opacity) -> Option<Self> {
+        if self.prefix == "text" && opacity == Opacity::Percent10 {
+            return None;
+        }
+        self.opacity = Some(opacity);
+        Some(self)
+    }
+}
+
+impl From<ColorOpacity> for Classes {
+    fn from(color: ColorOpacity) -> Self {
+        let mut classes = Classes::new();
+        classes.push(format!("{}-{}", color.prefix, color.color));
+        if let Some(opacity) = color.opacity {
+            classes.push(format!("{}-opacity-{}", color.prefix, opacity));
+        }
+        classes
+    }
+}
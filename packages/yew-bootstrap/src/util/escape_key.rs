@@ -0,0 +1,44 @@
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::KeyboardEvent;
+use yew::prelude::*;
+
+/// # Escape key hook
+/// Calls `callback` when the Escape key is pressed anywhere in the document, as long as
+/// `active` is `true`. No listener is registered while `active` is `false`.
+///
+/// The document listener is removed when `active` becomes `false` or the component is
+/// unmounted.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::util::use_escape_key;
+/// #[function_component]
+/// fn Panel() -> Html {
+///     let open = use_state(|| true);
+///     {
+///         let open = open.clone();
+///         use_escape_key(*open, Callback::from(move |_| open.set(false)));
+///     }
+///     html!{
+///         if *open { <div>{"Press Escape to close"}</div> }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_escape_key(active: bool, callback: Callback<KeyboardEvent>) {
+    use_effect_with((active, callback), |(active, callback)| {
+        let listener = active.then(|| {
+            let callback = callback.clone();
+            EventListener::new(&gloo::utils::document(), "keydown", move |event| {
+                if let Some(event) = event.dyn_ref::<KeyboardEvent>() {
+                    if event.key() == "Escape" {
+                        callback.emit(event.clone());
+                    }
+                }
+            })
+        });
+        move || drop(listener)
+    });
+}
@@ -0,0 +1,49 @@
+use std::fmt;
+
+/// # Align items utility
+/// Bootstrap alignment of flex items on the cross axis.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AlignItems {
+    Start,
+    End,
+    Center,
+    Baseline,
+    Stretch,
+}
+
+impl fmt::Display for AlignItems {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            AlignItems::Start => write!(f, "align-items-start"),
+            AlignItems::End => write!(f, "align-items-end"),
+            AlignItems::Center => write!(f, "align-items-center"),
+            AlignItems::Baseline => write!(f, "align-items-baseline"),
+            AlignItems::Stretch => write!(f, "align-items-stretch"),
+        }
+    }
+}
+
+/// # Justify content utility
+/// Bootstrap alignment of flex items on the main axis.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JustifyContent {
+    Start,
+    End,
+    Center,
+    Between,
+    Around,
+    Evenly,
+}
+
+impl fmt::Display for JustifyContent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            JustifyContent::Start => write!(f, "justify-content-start"),
+            JustifyContent::End => write!(f, "justify-content-end"),
+            JustifyContent::Center => write!(f, "justify-content-center"),
+            JustifyContent::Between => write!(f, "justify-content-between"),
+            JustifyContent::Around => write!(f, "justify-content-around"),
+            JustifyContent::Evenly => write!(f, "justify-content-evenly"),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use wasm_bindgen::prelude::*;
+use web_sys::{Element, IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+use yew::prelude::*;
+
+/// Options of [use_intersection], see the
+/// [IntersectionObserver documentation](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver/IntersectionObserver)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IntersectionOptions {
+    /// Margin around the viewport, in CSS margin syntax, e.g. `"100px 0px"`
+    pub root_margin: Option<AttrValue>,
+    /// Visible ratios, between 0.0 and 1.0, at which the state is updated. Empty
+    /// means only when the element enters or leaves the viewport.
+    pub threshold: Vec<f64>,
+}
+
+/// Visibility state returned by [use_intersection]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct IntersectionState {
+    /// Element is at least partly in the viewport
+    pub visible: bool,
+    /// Visible part of the element, between 0.0 and 1.0
+    pub ratio: f64,
+}
+
+/// # Intersection hook
+/// Returns whether the element referenced by `node_ref` is in the viewport, and which
+/// part of it is visible, using an `IntersectionObserver`. The component is re-rendered
+/// when the state changes at one of the `threshold` ratios.
+///
+/// The observer is disconnected when the component is unmounted.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::util::{use_intersection, IntersectionOptions};
+/// #[function_component]
+/// fn LazyImage() -> Html {
+///     let node_ref = use_node_ref();
+///     let options = IntersectionOptions { root_margin: Some("200px".into()), ..Default::default() };
+///     let intersection = use_intersection(node_ref.clone(), options);
+///     // Once loaded, keep the image even when it leaves the viewport
+///     let loaded = use_state(|| false);
+///     {
+///         let loaded = loaded.clone();
+///         use_effect_with(intersection.visible, move |visible| {
+///             if *visible {
+///                 loaded.set(true);
+///             }
+///         });
+///     }
+///     html!{
+///         <div ref={node_ref} style="min-height: 200px">
+///             if *loaded {
+///                 <img src="photo.jpg" alt="Photo" class="img-fluid" />
+///             }
+///         </div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_intersection(node_ref: NodeRef, options: IntersectionOptions) -> IntersectionState {
+    let state = use_state(IntersectionState::default);
+    {
+        let state = state.clone();
+        use_effect_with((node_ref, options), move |(node_ref, options)| {
+            let callback = Closure::<dyn FnMut(js_sys::Array)>::new(move |entries: js_sys::Array| {
+                // Only the last entry is the current state
+                if let Some(entry) = entries.iter().last() {
+                    let entry: IntersectionObserverEntry = entry.unchecked_into();
+                    state.set(IntersectionState {
+                        visible: entry.is_intersecting(),
+                        ratio: entry.intersection_ratio(),
+                    });
+                }
+            });
+
+            let init = IntersectionObserverInit::new();
+            if let Some(root_margin) = &options.root_margin {
+                init.set_root_margin(root_margin);
+            }
+            if !options.threshold.is_empty() {
+                let threshold: js_sys::Array = options.threshold.iter().map(|ratio| JsValue::from_f64(*ratio)).collect();
+                init.set_threshold(&threshold);
+            }
+
+            let observer = IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &init)
+                .map_err(|error| log::warn!("Cannot create IntersectionObserver: {:?}", error))
+                .ok();
+            if let (Some(observer), Some(element)) = (&observer, node_ref.cast::<Element>()) {
+                observer.observe(&element);
+            }
+
+            move || {
+                if let Some(observer) = observer {
+                    observer.disconnect();
+                }
+                drop(callback);
+            }
+        });
+    }
+    *state
+}
@@ -4,10 +4,28 @@ mod size;
 mod dimension;
 mod position;
 mod arrange;
+mod breakpoint;
+mod click_outside;
+mod escape_key;
+mod flex;
+mod intersection;
+mod reduced_motion;
+mod scroll_direction;
+mod spacing;
+mod z_index;
 
 pub use self::color::*;
 pub use self::include::*;
 pub use self::size::*;
 pub use self::dimension::*;
 pub use self::position::*;
-pub use self::arrange::*;
\ No newline at end of file
+pub use self::arrange::*;
+pub use self::breakpoint::*;
+pub use self::click_outside::*;
+pub use self::escape_key::*;
+pub use self::flex::*;
+pub use self::intersection::*;
+pub use self::reduced_motion::*;
+pub use self::scroll_direction::*;
+pub use self::spacing::*;
+pub use self::z_index::*;
\ No newline at end of file
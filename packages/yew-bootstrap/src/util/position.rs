@@ -1,5 +1,9 @@
 use std::fmt;
 
+use yew::Classes;
+
+use super::{ArrangeX, ArrangeY};
+
 /// # Position
 /// Bootstrap positions for elements, but they are not responsive.
 #[derive(Clone, PartialEq, Eq)]
@@ -22,3 +26,83 @@ impl fmt::Display for Position {
         }
     }
 }
+
+/// # Placement builder
+/// Combines a [Position] with edge offsets ([ArrangeX], [ArrangeY]) and an optional
+/// translation, to build the Bootstrap position utility classes.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::util::{ArrangeX, ArrangeY, Placement, Position};
+/// let centered = Placement::new(Position::Absolute)
+///     .x(ArrangeX::Start50)
+///     .y(ArrangeY::Top50)
+///     .translate_middle();
+/// assert_eq!(
+///     Classes::from(centered).to_string(),
+///     "position-absolute start-50 top-50 translate-middle"
+/// );
+/// let header = Placement::new(Position::Sticky).y(ArrangeY::Top0);
+/// assert_eq!(Classes::from(header).to_string(), "position-sticky top-0");
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Placement {
+    position: Position,
+    x: Option<ArrangeX>,
+    y: Option<ArrangeY>,
+    translate: Option<&'static str>,
+}
+
+impl Placement {
+    /// Placement with the given position and no offset
+    pub fn new(position: Position) -> Self {
+        Self { position, x: None, y: None, translate: None }
+    }
+
+    /// Horizontal offset (`start-*` or `end-*`)
+    pub fn x(mut self, x: ArrangeX) -> Self {
+        self.x = Some(x);
+        self
+    }
+
+    /// Vertical offset (`top-*` or `bottom-*`)
+    pub fn y(mut self, y: ArrangeY) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    /// Center the element on its offsets (`translate-middle`)
+    pub fn translate_middle(mut self) -> Self {
+        self.translate = Some("translate-middle");
+        self
+    }
+
+    /// Center the element horizontally on its offset (`translate-middle-x`)
+    pub fn translate_middle_x(mut self) -> Self {
+        self.translate = Some("translate-middle-x");
+        self
+    }
+
+    /// Center the element vertically on its offset (`translate-middle-y`)
+    pub fn translate_middle_y(mut self) -> Self {
+        self.translate = Some("translate-middle-y");
+        self
+    }
+}
+
+impl From<Placement> for Classes {
+    fn from(placement: Placement) -> Self {
+        let mut classes = Classes::new();
+        classes.push(placement.position.to_string());
+        if let Some(x) = placement.x {
+            classes.push(x.to_string());
+        }
+        if let Some(y) = placement.y {
+            classes.push(y.to_string());
+        }
+        if let Some(translate) = placement.translate {
+            classes.push(translate);
+        }
+        classes
+    }
+}
@@ -0,0 +1,9 @@
+/// True if the user asked the system to minimize animations
+/// (`prefers-reduced-motion: reduce` media query)
+pub fn prefers_reduced_motion() -> bool {
+    gloo::utils::window()
+        .match_media("(prefers-reduced-motion: reduce)")
+        .ok()
+        .flatten()
+        .is_some_and(|query| query.matches())
+}
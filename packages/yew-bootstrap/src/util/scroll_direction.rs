@@ -0,0 +1,68 @@
+use gloo::events::EventListener;
+use yew::prelude::*;
+
+/// Direction of the last scroll of the page
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollDirection {
+    /// Scrolling towards the top of the page, or not scrolled yet
+    #[default]
+    Up,
+    /// Scrolling towards the bottom of the page
+    Down,
+}
+
+/// Scroll state returned by [use_scroll_direction]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ScrollState {
+    /// Direction of the last scroll
+    pub direction: ScrollDirection,
+    /// True if the page is scrolled further than the offset given to [use_scroll_direction]
+    pub past_offset: bool,
+}
+
+/// # Scroll direction hook
+/// Returns the direction of the last vertical scroll of the page, and whether the page is
+/// scrolled further than `offset` pixels. The component is only re-rendered when one of
+/// them changes, not on every scroll event.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::util::{use_scroll_direction, ScrollDirection};
+/// #[function_component]
+/// fn BackToTop() -> Html {
+///     let scroll = use_scroll_direction(200.0);
+///     html!{
+///         if scroll.direction == ScrollDirection::Up && scroll.past_offset {
+///             <a href="#" class="btn btn-primary position-fixed bottom-0 end-0 m-3">{"Back to top"}</a>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_scroll_direction(offset: f64) -> ScrollState {
+    // Read by the listener, so it always uses the offset of the last render
+    let current_offset = use_mut_ref(|| offset);
+    *current_offset.borrow_mut() = offset;
+    // The page is only read in the effect, so the hook also works for server side rendering
+    let state = use_state_eq(ScrollState::default);
+    {
+        let state = state.clone();
+        use_effect_with((), move |_| {
+            let window = gloo::utils::window();
+            let last = std::cell::Cell::new(window.scroll_y().unwrap_or_default());
+            state.set(ScrollState {
+                direction: ScrollDirection::Up,
+                past_offset: last.get() > *current_offset.borrow(),
+            });
+            let listener = EventListener::new(&window, "scroll", move |_| {
+                let position = gloo::utils::window().scroll_y().unwrap_or_default();
+                let direction = if position > last.get() { ScrollDirection::Down } else { ScrollDirection::Up };
+                last.set(position);
+                state.set(ScrollState { direction, past_offset: position > *current_offset.borrow() });
+            });
+            move || drop(listener)
+        });
+    }
+    *state
+}
@@ -0,0 +1,32 @@
+use std::fmt;
+
+/// # Spacing scale
+/// Steps of the Bootstrap spacing utilities, such as `px-*` or `mt-*`, from `0` to `5`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Spacing {
+    /// `0`, no spacing
+    S0,
+    /// `1`, a quarter of the spacer
+    S1,
+    /// `2`, half of the spacer
+    S2,
+    /// `3`, the spacer
+    S3,
+    /// `4`, one and a half spacers
+    S4,
+    /// `5`, three spacers
+    S5,
+}
+
+impl fmt::Display for Spacing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Spacing::S0 => write!(f, "0"),
+            Spacing::S1 => write!(f, "1"),
+            Spacing::S2 => write!(f, "2"),
+            Spacing::S3 => write!(f, "3"),
+            Spacing::S4 => write!(f, "4"),
+            Spacing::S5 => write!(f, "5"),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+/// # Z-index levels
+/// Bootstrap `z-*` utilities, and the z-index levels used by Bootstrap components,
+/// to layer custom elements consistently with them.
+///
+/// The utilities (`z-n1` to `z-3`) require Bootstrap 5.3 or later, and are available as
+/// classes. Component levels have no class and are applied with an inline style.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::util::ZIndex;
+/// assert_eq!(ZIndex::Z1.class(), Some("z-1"));
+/// assert_eq!(ZIndex::Modal.value(), 1055);
+/// fn overlay() -> Html {
+///     // Above the navbar, below modals
+///     html!{ <div class="position-fixed" style={ZIndex::Fixed.css()}></div> }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZIndex {
+    /// `z-n1`, behind the siblings
+    N1,
+    /// `z-0`
+    Z0,
+    /// `z-1`
+    Z1,
+    /// `z-2`
+    Z2,
+    /// `z-3`
+    Z3,
+    /// Dropdown menus
+    Dropdown,
+    /// Sticky elements, like `sticky-top`
+    Sticky,
+    /// Fixed elements, like `fixed-top`
+    Fixed,
+    /// Backdrop of an offcanvas
+    OffcanvasBackdrop,
+    /// Offcanvas panels
+    Offcanvas,
+    /// Backdrop of a modal
+    ModalBackdrop,
+    /// Modal dialogs
+    Modal,
+    /// Popovers
+    Popover,
+    /// Tooltips
+    Tooltip,
+    /// Toasts
+    Toast,
+}
+
+impl ZIndex {
+    /// Numeric z-index value
+    pub fn value(&self) -> i32 {
+        match self {
+            ZIndex::N1 => -1,
+            ZIndex::Z0 => 0,
+            ZIndex::Z1 => 1,
+            ZIndex::Z2 => 2,
+            ZIndex::Z3 => 3,
+            ZIndex::Dropdown => 1000,
+            ZIndex::Sticky => 1020,
+            ZIndex::Fixed => 1030,
+            ZIndex::OffcanvasBackdrop => 1040,
+            ZIndex::Offcanvas => 1045,
+            ZIndex::ModalBackdrop => 1050,
+            ZIndex::Modal => 1055,
+            ZIndex::Popover => 1070,
+            ZIndex::Tooltip => 1080,
+            ZIndex::Toast => 1090,
+        }
+    }
+
+    /// Utility class for this level, `None` for component levels
+    pub fn class(&self) -> Option<&'static str> {
+        match self {
+            ZIndex::N1 => Some("z-n1"),
+            ZIndex::Z0 => Some("z-0"),
+            ZIndex::Z1 => Some("z-1"),
+            ZIndex::Z2 => Some("z-2"),
+            ZIndex::Z3 => Some("z-3"),
+            _ => None,
+        }
+    }
+
+    /// Inline style setting this z-index
+    pub fn css(&self) -> String {
+        format!("z-index: {}", self.value())
+    }
+}